@@ -0,0 +1,67 @@
+//! Compares an `i64` inner against the standard `i128` inner, in a loop mixing all four operators.
+//! Run with `cargo bench --bench backing`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use yolol_number::number::YololNumber as GenericNumber;
+use yolol_number::prelude::*;
+use yolol_number::traits::ArgBounds;
+
+const LEN: usize = 4096;
+const ROUNDS: u32 = 500;
+
+/// Runs the closure enough times to get a stable measurement, returning the nanoseconds taken per element.
+fn time_per_element(mut run: impl FnMut()) -> f64
+{
+    // Warms up the caches before anything is timed
+    for _ in 0..ROUNDS / 10
+    {
+        run();
+    }
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS
+    {
+        run();
+    }
+
+    start.elapsed().as_secs_f64() * 1e9 / (<f64 as From<u32>>::from(ROUNDS) * LEN as f64)
+}
+
+/// Times a yolol style update of every element, `(a + b) * c / d - a`.
+fn mixed_ops<T: YololOps>(raws: &[i64]) -> f64
+where i64: ArgBounds<T>
+{
+    let nums: Vec<GenericNumber<T>> = raws.iter().map(|&raw| GenericNumber::from_raw(RawInner(raw))).collect();
+    let mut out = vec![GenericNumber::<T>::default(); LEN];
+
+    let b = GenericNumber::<T>::from_raw(RawInner(12_345_i64));
+    let c = GenericNumber::<T>::from_raw(RawInner(2_500_i64));
+    let d = GenericNumber::<T>::from_raw(RawInner(-1_750_i64));
+
+    time_per_element(|| {
+        let (b, c, d) = black_box((b, c, d));
+
+        for (out, &a) in out.iter_mut().zip(black_box(&nums))
+        {
+            *out = (a + b) * c / d - a;
+        }
+        black_box(&mut out);
+    })
+}
+
+fn main()
+{
+    // Values spread across the range players actually use, which rarely saturate
+    let raws: Vec<i64> = (0..LEN as i64)
+        .map(|i| (i * 7_919).wrapping_mul(2_654_435_761) % 1_000_000_000_000)
+        .collect();
+
+    let wide = mixed_ops::<i128>(&raws);
+    let narrow = mixed_ops::<i64>(&raws);
+
+    println!("{:<24} {:>6.2}ns per element", "mixed ops (i128 inner)", wide);
+    println!("{:<24} {:>6.2}ns per element", "mixed ops (i64 inner)", narrow);
+    println!("i64 takes {:.0}% less time", (1.0 - narrow / wide) * 100.0);
+}
@@ -0,0 +1,65 @@
+/*
+ * C interface to yolol_number, giving the exact same results as the game.
+ *
+ * Numbers are passed as their raw inner in an int64_t, which is the value times 1000.
+ * So 1.5 is passed as 1500. Every int64_t is a valid number.
+ *
+ * Every function returns a yolol_status, and writes its result through the last pointer.
+ * Link against the staticlib or cdylib built by cargo.
+ */
+
+#ifndef YOLOL_NUMBER_H
+#define YOLOL_NUMBER_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The size of buffer yolol_format needs to fit any number, including the nul terminator. */
+#define YOLOL_FORMAT_BUFFER_LEN 23
+
+typedef enum yolol_status
+{
+    /* The function succeeded. */
+    YOLOL_OK = 0,
+    /* A pointer that's required was null, so nothing was written. */
+    YOLOL_NULL_POINTER = 1,
+    /* The right side of a division or modulo was zero. The output is still written with zero, by the same convention
+       as the Rust crate's operators. The live game raises a runtime error instead, so check for this status to get that. */
+    YOLOL_DIVIDE_BY_ZERO = 2,
+    /* The string wasn't valid utf8 or wasn't a valid number, so nothing was written. */
+    YOLOL_PARSE_ERROR = 3,
+    /* The buffer was too small to fit the formatted number, so nothing was written. */
+    YOLOL_BUFFER_TOO_SMALL = 4,
+} yolol_status;
+
+/* Arithmetic, saturating at the bounds the same as the game. */
+yolol_status yolol_add(int64_t left, int64_t right, int64_t *out);
+yolol_status yolol_sub(int64_t left, int64_t right, int64_t *out);
+yolol_status yolol_mul(int64_t left, int64_t right, int64_t *out);
+yolol_status yolol_div(int64_t left, int64_t right, int64_t *out);
+yolol_status yolol_mod(int64_t left, int64_t right, int64_t *out);
+
+/* Square root and trig. Trig works in degrees, the same as the game. */
+yolol_status yolol_sqrt(int64_t input, int64_t *out);
+yolol_status yolol_sin(int64_t input, int64_t *out);
+yolol_status yolol_cos(int64_t input, int64_t *out);
+yolol_status yolol_tan(int64_t input, int64_t *out);
+yolol_status yolol_asin(int64_t input, int64_t *out);
+yolol_status yolol_acos(int64_t input, int64_t *out);
+yolol_status yolol_atan(int64_t input, int64_t *out);
+
+/* Parses len bytes of string, which doesn't need a nul terminator. */
+yolol_status yolol_parse(const char *string, size_t len, int64_t *out);
+
+/* Formats the number into buf with a nul terminator. The length without the terminator is written to written if it isn't null. */
+yolol_status yolol_format(int64_t input, char *buf, size_t buf_len, size_t *written);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* YOLOL_NUMBER_H */
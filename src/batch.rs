@@ -0,0 +1,202 @@
+use num_traits::{AsPrimitive, Bounded};
+
+use crate::number::YololNumber;
+use crate::number::report::{self, OpKind, OpReport};
+use crate::traits::{YololOps, YololRange};
+
+// Each of these gives exactly the same results as applying the scalar operator to every element.
+// Only addition and subtraction are actually faster, as checking for saturation after the fact leaves
+// plain loops over the inner values that the compiler is able to vectorize, see `benches/batch.rs`.
+// The rest are there for convenience, and apart from the trig functions they tell the observer only
+// once per call, with the reports of every element combined.
+
+/// Panics if the lengths of the input and output slices don't match.
+fn check_lengths(name: &str, input: usize, out: usize)
+{
+    assert!(input == out, "[batch::{}] Input has {} elements but the output has {}!", name, input, out);
+}
+
+/// Adds or subtracts each pair of elements, saturating at the bounds the same as the operator.
+fn add_or_sub<T: YololOps, R: YololRange>(
+    kind: OpKind,
+    left: &[YololNumber<T, R>],
+    right: &[YololNumber<T, R>],
+    out: &mut [YololNumber<T, R>]
+)
+{
+    let subtract = kind == OpKind::Sub;
+    let name = if subtract { "sub_slices" } else { "add_slices" };
+    check_lengths(name, left.len(), out.len());
+    check_lengths(name, right.len(), out.len());
+
+    let min = YololNumber::<T, R>::min_value().to_raw().0;
+    let max = YololNumber::<T, R>::max_value().to_raw().0;
+
+    // Saturating at the bounds of the inner type and then clamping to the range gives the same result as
+    // the operator. Keeping the loop to just that is what lets the compiler vectorize it. When the range
+    // fits in half the inner type, like the standard range in an `i128`, the sum can't overflow the inner
+    // type at all, so the saturating can be skipped too, which is what makes this faster for an `i128`.
+    let two = T::one() + T::one();
+    let never_overflows = max <= T::max_value() / two && min >= T::min_value() / two;
+
+    if never_overflows
+    {
+        clamped_kernel(left, right, out, min, max, |left, right| if subtract { left - right } else { left + right });
+    }
+    else
+    {
+        clamped_kernel(left, right, out, min, max, |left, right| {
+            if subtract { left.saturating_sub(&right) } else { left.saturating_add(&right) }
+        });
+    }
+
+    // Only results sitting on a bound could have saturated, which is rare enough to check afterwards
+    let at_bound = out.iter()
+        .fold(false, |found, num| found | (num.to_raw().0 == max) | (num.to_raw().0 == min));
+
+    if !at_bound
+    {
+        return;
+    }
+
+    let mut op_report = OpReport::default();
+
+    for ((out, left), right) in out.iter().zip(left).zip(right)
+    {
+        let result = out.to_raw().0;
+        if result != max && result != min
+        {
+            continue;
+        }
+
+        let (left, right) = (left.to_raw().0, right.to_raw().0);
+        let exact = if subtract { left.checked_sub(&right) } else { left.checked_add(&right) };
+
+        // A result on a bound saturated if it isn't what the math actually came to
+        if exact != Some(result)
+        {
+            op_report |= OpReport::saturation(result == max);
+        }
+    }
+
+    report::notify(kind, op_report);
+}
+
+/// Applies the operation to each pair of raw inners and clamps the result to the range, without any reporting.
+#[inline(always)]
+fn clamped_kernel<T, R, F>(left: &[YololNumber<T, R>], right: &[YololNumber<T, R>], out: &mut [YololNumber<T, R>], min: T, max: T, op: F)
+where
+    T: YololOps,
+    R: YololRange,
+    F: Fn(T, T) -> T
+{
+    for ((out, left), right) in out.iter_mut().zip(left).zip(right)
+    {
+        let result = op(left.to_raw().0, right.to_raw().0);
+        *out = YololNumber::from_inner_unchecked(result.clamp(min, max));
+    }
+}
+
+/// Applies the operation to each pair of elements, combining the reports.
+fn apply_pairs<T, R, F>(kind: OpKind, name: &str, left: &[YololNumber<T, R>], right: &[YololNumber<T, R>], out: &mut [YololNumber<T, R>], op: F)
+where
+    T: YololOps,
+    R: YololRange,
+    F: Fn(YololNumber<T, R>, YololNumber<T, R>) -> (YololNumber<T, R>, OpReport)
+{
+    check_lengths(name, left.len(), out.len());
+    check_lengths(name, right.len(), out.len());
+
+    let mut combined = OpReport::default();
+
+    for ((out, &left), &right) in out.iter_mut().zip(left).zip(right)
+    {
+        let (result, op_report) = op(left, right);
+        *out = result;
+        combined |= op_report;
+    }
+
+    report::notify(kind, combined);
+}
+
+/// Adds each pair of elements into `out`, the same as `+`. Panics if the lengths don't match.
+pub fn add_slices<T: YololOps, R: YololRange>(left: &[YololNumber<T, R>], right: &[YololNumber<T, R>], out: &mut [YololNumber<T, R>])
+{
+    add_or_sub(OpKind::Add, left, right, out);
+}
+
+/// Subtracts each pair of elements into `out`, the same as `-`. Panics if the lengths don't match.
+pub fn sub_slices<T: YololOps, R: YololRange>(left: &[YololNumber<T, R>], right: &[YololNumber<T, R>], out: &mut [YololNumber<T, R>])
+{
+    add_or_sub(OpKind::Sub, left, right, out);
+}
+
+/// Multiplies each pair of elements into `out`, the same as `*`. Panics if the lengths don't match.
+pub fn mul_slices<T: YololOps, R: YololRange>(left: &[YololNumber<T, R>], right: &[YololNumber<T, R>], out: &mut [YololNumber<T, R>])
+{
+    apply_pairs(OpKind::Mul, "mul_slices", left, right, out, YololNumber::mul_with_report);
+}
+
+/// Divides each pair of elements into `out`, the same as `/`. Panics if the lengths don't match.
+pub fn div_slices<T: YololOps, R: YololRange>(left: &[YololNumber<T, R>], right: &[YololNumber<T, R>], out: &mut [YololNumber<T, R>])
+{
+    apply_pairs(OpKind::Div, "div_slices", left, right, out, YololNumber::div_with_report);
+}
+
+/// Multiplies every element by the factor into `out`, the same as `*`. Panics if the lengths don't match.
+pub fn scale<T: YololOps, R: YololRange>(input: &[YololNumber<T, R>], factor: YololNumber<T, R>, out: &mut [YololNumber<T, R>])
+{
+    check_lengths("scale", input.len(), out.len());
+
+    let mut combined = OpReport::default();
+
+    for (out, &num) in out.iter_mut().zip(input)
+    {
+        let (result, op_report) = num.mul_with_report(factor);
+        *out = result;
+        combined |= op_report;
+    }
+
+    report::notify(OpKind::Mul, combined);
+}
+
+/// Sums the elements, the same as folding them with `+` from zero. Since every step
+/// saturates, the order matters, so this adds them from first to last.
+pub fn sum_saturating<T: YololOps, R: YololRange>(input: &[YololNumber<T, R>]) -> YololNumber<T, R>
+{
+    let mut combined = OpReport::default();
+
+    let sum = input.iter().fold(YololNumber::default(), |sum, &num| {
+        let (result, op_report) = sum.add_with_report(num);
+        combined |= op_report;
+        result
+    });
+
+    report::notify(OpKind::Add, combined);
+    sum
+}
+
+/// Implements a batch version of a trig method. These are just the method called on each element in turn,
+/// which reports per element, so there's nothing to combine and nothing to gain over a loop.
+macro_rules! impl_trig_slice {
+    ( $( $name:ident => $method:ident ),* ) => {
+        $(
+            #[doc = concat!("Takes the `", stringify!($method), "` of every element into `out`. Panics if the lengths don't match.")]
+            pub fn $name<T, R>(input: &[YololNumber<T, R>], out: &mut [YololNumber<T, R>])
+            where
+                T: YololOps + AsPrimitive<f64>,
+                R: YololRange,
+                f64: AsPrimitive<T>
+            {
+                check_lengths(stringify!($name), input.len(), out.len());
+
+                for (out, num) in out.iter_mut().zip(input)
+                {
+                    *out = num.$method();
+                }
+            }
+        )*
+    }
+}
+
+impl_trig_slice!(sin_slice => sin, cos_slice => cos, tan_slice => tan);
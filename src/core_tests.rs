@@ -0,0 +1,96 @@
+// These run with and without the `std` feature. Without it, they're the only tests covering the
+// `no_std` paths, which are the float math in `float_math::core_impl` and parsing with `split_parts`.
+
+// The way I write numbers to make them easy to read makes clippy angry
+#![allow(clippy::zero_prefixed_literal)]
+#![allow(clippy::inconsistent_digit_grouping)]
+
+extern crate std;
+
+use std::string::ToString;
+
+use crate::prelude::*;
+
+#[test]
+fn core_math_test()
+{
+    use crate::float_math::core_impl as core_math;
+
+    let close = |ours: f64, theirs: f64| {
+        (ours.is_nan() && theirs.is_nan()) || ours == theirs || (ours - theirs).abs() <= 1e-9 * theirs.abs().max(1.0)
+    };
+
+    // Every yolol-ish value from -1000 to 1000 in steps of 0.125, plus some awkward ones
+    let values = (-8000..=8000).map(|n: i32| <f64 as From<i32>>::from(n) / 8.0)
+        .chain([0.001, -0.001, 0.5, -0.5, 2.5, -2.5, 1e10, -1e10, 9_223_372_036_854_775.807].iter().copied());
+
+    for x in values
+    {
+        assert_eq!(core_math::round(x), x.round(), "round({})", x);
+        assert_eq!(core_math::ceil(x), x.ceil(), "ceil({})", x);
+        assert_eq!(core_math::fract(x), x.fract(), "fract({})", x);
+
+        assert!(close(core_math::sqrt(x), x.sqrt()), "sqrt({})", x);
+        // Huge angles are reduced exactly in degrees, so they're only comparable to std's radians nearby
+        if x.abs() <= 1000.0
+        {
+            assert!(close(core_math::sin_degrees(x), x.to_radians().sin()), "sin({})", x);
+            assert!(close(core_math::cos_degrees(x), x.to_radians().cos()), "cos({})", x);
+        }
+
+        assert!(close(core_math::asin_degrees(x / 1000.0), (x / 1000.0).asin().to_degrees()), "asin({})", x);
+        assert!(close(core_math::acos_degrees(x / 1000.0), (x / 1000.0).acos().to_degrees()), "acos({})", x);
+        assert!(close(core_math::atan_degrees(x), x.atan().to_degrees()), "atan({})", x);
+
+        if x.abs() <= 1000.0 && (x - 90.0) % 180.0 != 0.0
+        {
+            assert!(close(core_math::tan_degrees(x), x.to_radians().tan()), "tan({})", x);
+        }
+
+        for &y in &[0.0, 1.0, 2.0, 3.0, -1.0, 0.5, -0.5, 1.5, 10.0]
+        {
+            assert!(close(core_math::powf(x, y), x.powf(y)), "powf({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn core_number_test()
+{
+    let num = |input: &str| input.parse::<YololNumber>().unwrap();
+    let raw = |inner: i128| YololNumber::from_raw(RawInner(inner));
+
+    // Parsing goes through `split_parts` without `std`
+    assert_eq!(num("12.0340"), raw(12_034));
+    assert_eq!(num("-007.700"), raw(-7_700));
+    assert_eq!(num("+1.0009"), raw(1_000));
+    assert_eq!(num("99999999999999999"), YololNumber::max_value());
+    assert_eq!("1.5.".parse::<YololNumber>(), Err(FromStrError::InputVerificationFailure));
+    assert_eq!(".5".parse::<YololNumber>(), Err(FromStrError::InputVerificationFailure));
+
+    // The float math goes through `core_impl` without `std`
+    assert_eq!(num("2").sqrt(), raw(1_414));
+    assert_eq!(num("2").pow(num("10")), raw(1024_000));
+    assert_eq!(num("2").pow(num("0.5")), raw(1_414));
+    assert_eq!(num("30").sin(), raw(0_500));
+    assert_eq!(num("60").cos(), raw(0_500));
+    assert_eq!(num("45").tan(), raw(1_000));
+    assert_eq!(num("0.5").asin(), raw(30_000));
+    assert_eq!(num("0.5").acos(), raw(60_000));
+    assert_eq!(num("1").atan(), raw(45_000));
+    assert_eq!(YololNumber::from_float(-2.4995), raw(-2_500));
+
+    let mut buf = [0_u8; YololNumber::MAX_FORMATTED_LEN];
+    assert_eq!(num("-12.5").format_into(&mut buf), "-12.5");
+    assert_eq!(num("-12.5").to_string(), "-12.5");
+}
+
+#[test]
+fn core_error_display_test()
+{
+    assert_eq!(ConversionError::NotANumber.to_string(), "Input is NaN, which can't be expressed as a Yolol number.");
+    assert_eq!(FromStrError::NoMainDigits.to_string(), "No main digits (left of the decimal) were matched in regex verification.");
+    assert_eq!(FromStrRadixError::InvalidRadix(40).to_string(), "Radix 40 isn't supported, it must be between 2 and 36.");
+    assert_eq!(FromStrRadixError::InvalidDigit('z').to_string(), "Found the character 'z', which isn't a valid digit in the given radix.");
+    assert_eq!(PackError::EmptyRange(2).to_string(), "Field 2 has an empty range.");
+}
@@ -0,0 +1,177 @@
+use core::{slice, str};
+
+use crate::number::conversions::FORMAT_BUFFER_LEN;
+
+/// The numbers crossing the boundary, which are passed as their raw inner in an `int64_t`.
+/// Every `int64_t` is a valid number, since the range in the game is the range of an `i64`.
+/// The math is still done in the standard `YololNumber`, so the results match it exactly.
+type Number = crate::YololNumber;
+
+/// The size of buffer `yolol_format` needs to fit any number, which is the length
+/// of the longest one, `-9223372036854775.808`, plus the nul terminator.
+pub const YOLOL_FORMAT_BUFFER_LEN: usize = 23;
+
+/// The status every function returns, matching `yolol_status` in `include/yolol_number.h`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YololStatus
+{
+    /// The function succeeded.
+    Ok = 0,
+    /// A pointer that's required was null, so nothing was written.
+    NullPointer = 1,
+    /// The right side of a division or modulo was zero. The output is still written with zero,
+    /// by the same convention as the crate's operators. The live game raises a runtime error
+    /// instead, see `Semantics::STRICT`, so check for this status to get that behaviour.
+    DivideByZero = 2,
+    /// The string wasn't valid utf8 or wasn't a valid number, so nothing was written.
+    ParseError = 3,
+    /// The buffer was too small to fit the formatted number, so nothing was written.
+    BufferTooSmall = 4,
+}
+
+/// Writes the value through the pointer, failing if it's null.
+unsafe fn write_out<V>(out: *mut V, value: V) -> YololStatus
+{
+    match out.as_mut()
+    {
+        Some(out) => {
+            *out = value;
+            YololStatus::Ok
+        },
+
+        None => YololStatus::NullPointer,
+    }
+}
+
+fn from_raw(raw: i64) -> Number
+{
+    Number::from_inner_const(i128::from(raw))
+}
+
+fn to_raw(num: Number) -> i64
+{
+    // The standard range is the range of an `i64`, so this never truncates
+    num.to_raw().0 as i64
+}
+
+/// Implements the binary operators that can't fail.
+macro_rules! impl_ffi_binary {
+    ( $( $name:ident => $op:tt ),* ) => {
+        $(
+            #[doc = concat!("Applies `", stringify!($op), "` to the raw inners the same as the game, writing the raw inner of the result to `out`.")]
+            ///
+            /// # Safety
+            /// `out` has to be null or valid to write an `int64_t` to.
+            #[no_mangle]
+            pub unsafe extern "C" fn $name(left: i64, right: i64, out: *mut i64) -> YololStatus
+            {
+                write_out(out, to_raw(from_raw(left) $op from_raw(right)))
+            }
+        )*
+    }
+}
+
+/// Implements the division operators, which report dividing by zero.
+macro_rules! impl_ffi_division {
+    ( $( $name:ident => $op:tt ),* ) => {
+        $(
+            #[doc = concat!("Applies `", stringify!($op), "` to the raw inners the same as the game, writing the raw inner of the result to `out`.")]
+            /// Dividing by zero writes zero and returns `DivideByZero`.
+            ///
+            /// # Safety
+            /// `out` has to be null or valid to write an `int64_t` to.
+            #[no_mangle]
+            pub unsafe extern "C" fn $name(left: i64, right: i64, out: *mut i64) -> YololStatus
+            {
+                let status = write_out(out, to_raw(from_raw(left) $op from_raw(right)));
+
+                if status == YololStatus::Ok && right == 0 { YololStatus::DivideByZero } else { status }
+            }
+        )*
+    }
+}
+
+/// Implements the unary methods, which can't fail.
+macro_rules! impl_ffi_unary {
+    ( $( $name:ident => $method:ident ),* ) => {
+        $(
+            #[doc = concat!("Takes the `", stringify!($method), "` of the raw inner the same as the game, writing the raw inner of the result to `out`.")]
+            ///
+            /// # Safety
+            /// `out` has to be null or valid to write an `int64_t` to.
+            #[no_mangle]
+            pub unsafe extern "C" fn $name(input: i64, out: *mut i64) -> YololStatus
+            {
+                write_out(out, to_raw(from_raw(input).$method()))
+            }
+        )*
+    }
+}
+
+impl_ffi_binary!(yolol_add => +, yolol_sub => -, yolol_mul => *);
+impl_ffi_division!(yolol_div => /, yolol_mod => %);
+impl_ffi_unary!(
+    yolol_sqrt => sqrt,
+    yolol_sin => sin,
+    yolol_cos => cos,
+    yolol_tan => tan,
+    yolol_asin => asin,
+    yolol_acos => acos,
+    yolol_atan => atan
+);
+
+/// Parses the `len` bytes at `string` the same as the game, writing the raw inner to `out`.
+/// The string doesn't need a nul terminator.
+///
+/// # Safety
+/// `string` has to be null or valid to read `len` bytes from, and `out` has to be null or valid to write an `int64_t` to.
+#[no_mangle]
+pub unsafe extern "C" fn yolol_parse(string: *const u8, len: usize, out: *mut i64) -> YololStatus
+{
+    if string.is_null() || out.is_null()
+    {
+        return YololStatus::NullPointer;
+    }
+
+    let bytes = slice::from_raw_parts(string, len);
+
+    match str::from_utf8(bytes).ok().and_then(|string| string.parse::<Number>().ok())
+    {
+        Some(num) => write_out(out, to_raw(num)),
+        None => YololStatus::ParseError,
+    }
+}
+
+/// Formats the raw inner the same as the game into `buf`, with a nul terminator. Writes the length
+/// without the terminator to `written` if it isn't null. A buffer of `YOLOL_FORMAT_BUFFER_LEN` always fits.
+///
+/// # Safety
+/// `buf` has to be null or valid to write `buf_len` bytes to, and `written` has to be null or valid to write a `size_t` to.
+#[no_mangle]
+pub unsafe extern "C" fn yolol_format(input: i64, buf: *mut u8, buf_len: usize, written: *mut usize) -> YololStatus
+{
+    if buf.is_null()
+    {
+        return YololStatus::NullPointer;
+    }
+
+    let mut formatted = [0_u8; FORMAT_BUFFER_LEN];
+    let string = from_raw(input).format_into(&mut formatted);
+
+    if string.len() >= buf_len
+    {
+        return YololStatus::BufferTooSmall;
+    }
+
+    let buf = slice::from_raw_parts_mut(buf, buf_len);
+    buf[..string.len()].copy_from_slice(string.as_bytes());
+    buf[string.len()] = 0;
+
+    if !written.is_null()
+    {
+        *written = string.len();
+    }
+
+    YololStatus::Ok
+}
@@ -0,0 +1,409 @@
+//! The float functions a `YololNumber` needs that `core` doesn't have.
+//!
+//! With the `std` feature these are just the std methods. Without it they're implemented here, using the
+//! same approach as fdlibm, and give the same results as std once rounded to 3 decimal places, apart from
+//! the occasional last digit. The trig functions take and give degrees, as that's what yolol uses, which
+//! lets the implementations here reduce the angle exactly before converting it to radians.
+
+#[cfg(feature = "std")]
+pub(crate) use self::std_impl::*;
+
+#[cfg(not(feature = "std"))]
+pub(crate) use self::core_impl::*;
+
+#[cfg(feature = "std")]
+mod std_impl
+{
+    pub(crate) fn round(x: f64) -> f64 { x.round() }
+    pub(crate) fn ceil(x: f64) -> f64 { x.ceil() }
+    pub(crate) fn fract(x: f64) -> f64 { x.fract() }
+
+    pub(crate) fn sqrt(x: f64) -> f64 { x.sqrt() }
+    pub(crate) fn powf(x: f64, y: f64) -> f64 { x.powf(y) }
+
+    pub(crate) fn sin_degrees(x: f64) -> f64 { x.to_radians().sin() }
+    pub(crate) fn cos_degrees(x: f64) -> f64 { x.to_radians().cos() }
+    pub(crate) fn tan_degrees(x: f64) -> f64 { x.to_radians().tan() }
+
+    pub(crate) fn asin_degrees(x: f64) -> f64 { x.asin().to_degrees() }
+    pub(crate) fn acos_degrees(x: f64) -> f64 { x.acos().to_degrees() }
+    pub(crate) fn atan_degrees(x: f64) -> f64 { x.atan().to_degrees() }
+}
+
+// The polynomial coefficients are copied digit for digit from fdlibm, so they stay as written
+#[cfg(any(test, not(feature = "std")))]
+#[allow(clippy::excessive_precision, clippy::approx_constant)]
+pub(crate) mod core_impl
+{
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    /// Every float at least this large is already a whole number.
+    const WHOLE: f64 = 4_503_599_627_370_496.0;
+
+    pub(crate) fn trunc(x: f64) -> f64
+    {
+        if x.is_nan() || x.abs() >= WHOLE { x } else { (x as i64) as f64 }
+    }
+
+    /// Rounds half way cases away from zero, the same as std.
+    pub(crate) fn round(x: f64) -> f64
+    {
+        let whole = trunc(x);
+
+        // The difference is exact, so this doesn't get fooled by the addition rounding like `trunc(x + 0.5)` would
+        if (x - whole).abs() >= 0.5 { whole + x.signum() } else { whole }
+    }
+
+    pub(crate) fn ceil(x: f64) -> f64
+    {
+        let whole = trunc(x);
+        if x > whole { whole + 1.0 } else { whole }
+    }
+
+    pub(crate) fn fract(x: f64) -> f64
+    {
+        x - trunc(x)
+    }
+
+    pub(crate) fn sqrt(x: f64) -> f64
+    {
+        if x.is_nan() || x < 0.0
+        {
+            return f64::NAN;
+        }
+
+        if x == 0.0 || x.is_infinite()
+        {
+            return x;
+        }
+
+        // Halving the exponent gets within a few percent, and each step of Newton's method doubles the correct bits
+        let mut y = f64::from_bits((x.to_bits() >> 1) + 0x1FF8_0000_0000_0000);
+        for _ in 0..6
+        {
+            y = 0.5 * (y + x / y);
+        }
+
+        y
+    }
+
+    pub(crate) fn powf(x: f64, y: f64) -> f64
+    {
+        if y == 0.0 || x == 1.0
+        {
+            return 1.0;
+        }
+
+        if x.is_nan() || y.is_nan()
+        {
+            return f64::NAN;
+        }
+
+        let whole_power = trunc(y) == y;
+
+        // Whole powers are done by repeated squaring, which is exact for the small results yolol usually sees
+        if whole_power && y.abs() <= 1024.0
+        {
+            let mut base = if y < 0.0 { 1.0 / x } else { x };
+            let mut power = y.abs() as u32;
+            let mut out = 1.0;
+
+            while power > 0
+            {
+                if power & 1 == 1 { out *= base; }
+                base *= base;
+                power >>= 1;
+            }
+
+            return out;
+        }
+
+        if x < 0.0
+        {
+            if !whole_power
+            {
+                return f64::NAN;
+            }
+
+            // Powers this large are always even, as a float that big has no fraction or ones bit left
+            let odd = y.abs() < 2.0 * WHOLE && (y % 2.0) != 0.0;
+            let out = powf(-x, y);
+            return if odd { -out } else { out };
+        }
+
+        if x == 0.0
+        {
+            return if y > 0.0 { 0.0 } else { f64::INFINITY };
+        }
+
+        exp(y * ln(x))
+    }
+
+    /// Reduces an angle in degrees to radians within 45 degrees of zero, along with which quarter turn it's in.
+    fn reduce_degrees(x: f64) -> (f64, u8)
+    {
+        // Both steps are exact, which is why the reduction is done before converting to radians
+        let turn = x % 360.0;
+        let quarter = round(turn / 90.0);
+        let angle = turn - quarter * 90.0;
+
+        (angle * (PI / 180.0), (quarter as i8).rem_euclid(4) as u8)
+    }
+
+    /// Sine on `-pi/4` to `pi/4`.
+    fn kernel_sin(x: f64) -> f64
+    {
+        const S1: f64 = -1.666_666_666_666_663_243_48e-01;
+        const S2: f64 = 8.333_333_333_322_489_461_24e-03;
+        const S3: f64 = -1.984_126_982_985_794_931_34e-04;
+        const S4: f64 = 2.755_731_370_707_006_767_89e-06;
+        const S5: f64 = -2.505_076_025_340_686_341_95e-08;
+        const S6: f64 = 1.589_690_995_211_550_102_21e-10;
+
+        let z = x * x;
+        let r = S2 + z * (S3 + z * (S4 + z * (S5 + z * S6)));
+        x + z * x * (S1 + z * r)
+    }
+
+    /// Cosine on `-pi/4` to `pi/4`.
+    fn kernel_cos(x: f64) -> f64
+    {
+        const C1: f64 = 4.166_666_666_666_660_190_37e-02;
+        const C2: f64 = -1.388_888_888_887_410_957_49e-03;
+        const C3: f64 = 2.480_158_728_947_672_941_78e-05;
+        const C4: f64 = -2.755_731_435_139_066_330_35e-07;
+        const C5: f64 = 2.087_572_321_298_174_827_90e-09;
+        const C6: f64 = -1.135_964_755_778_819_482_65e-11;
+
+        let z = x * x;
+        let r = z * (C1 + z * (C2 + z * (C3 + z * (C4 + z * (C5 + z * C6)))));
+        let half = 0.5 * z;
+        let w = 1.0 - half;
+        w + (((1.0 - w) - half) + z * r)
+    }
+
+    pub(crate) fn sin_degrees(x: f64) -> f64
+    {
+        let (angle, quarter) = reduce_degrees(x);
+        match quarter
+        {
+            0 => kernel_sin(angle),
+            1 => kernel_cos(angle),
+            2 => -kernel_sin(angle),
+            _ => -kernel_cos(angle),
+        }
+    }
+
+    pub(crate) fn cos_degrees(x: f64) -> f64
+    {
+        let (angle, quarter) = reduce_degrees(x);
+        match quarter
+        {
+            0 => kernel_cos(angle),
+            1 => -kernel_sin(angle),
+            2 => -kernel_cos(angle),
+            _ => kernel_sin(angle),
+        }
+    }
+
+    /// Exactly on an asymptote this gives infinity with the sign of the input, where std gives a huge
+    /// number of the same sign that depends on how far off the conversion to radians was.
+    pub(crate) fn tan_degrees(x: f64) -> f64
+    {
+        let (angle, quarter) = reduce_degrees(x);
+
+        if quarter % 2 == 0
+        {
+            kernel_sin(angle) / kernel_cos(angle)
+        }
+        else if angle == 0.0
+        {
+            f64::INFINITY.copysign(x)
+        }
+        else
+        {
+            -kernel_cos(angle) / kernel_sin(angle)
+        }
+    }
+
+    pub(crate) fn asin_degrees(x: f64) -> f64
+    {
+        // Outside of -1 to 1 the square root gives NaN, and at the ends dividing by zero gives the infinity atan wants
+        atan(x / sqrt((1.0 - x) * (1.0 + x))).to_degrees()
+    }
+
+    pub(crate) fn acos_degrees(x: f64) -> f64
+    {
+        (2.0 * atan(sqrt((1.0 - x) / (1.0 + x)))).to_degrees()
+    }
+
+    pub(crate) fn atan_degrees(x: f64) -> f64
+    {
+        atan(x).to_degrees()
+    }
+
+    fn atan(x: f64) -> f64
+    {
+        const ATAN_HI: [f64; 4] = [
+            4.636_476_090_008_060_935_15e-01,
+            7.853_981_633_974_482_789_99e-01,
+            9.827_937_232_473_290_540_82e-01,
+            1.570_796_326_794_896_558_00e+00,
+        ];
+        const ATAN_LO: [f64; 4] = [
+            2.269_877_745_296_168_709_24e-17,
+            3.061_616_997_868_383_017_93e-17,
+            1.390_331_103_123_099_845_16e-17,
+            6.123_233_995_736_766_035_87e-17,
+        ];
+        const AT: [f64; 11] = [
+            3.333_333_333_333_293_180_27e-01,
+            -1.999_999_999_987_648_324_76e-01,
+            1.428_571_427_250_346_637_11e-01,
+            -1.111_111_040_546_235_578_80e-01,
+            9.090_887_133_436_506_561_96e-02,
+            -7.691_876_205_044_829_994_95e-02,
+            6.661_073_137_387_531_206_69e-02,
+            -5.833_570_133_790_573_486_45e-02,
+            4.976_877_994_615_932_360_17e-02,
+            -3.653_157_274_421_691_552_70e-02,
+            1.628_582_011_536_578_236_23e-02,
+        ];
+
+        if x.is_nan()
+        {
+            return x;
+        }
+
+        let negative = x.is_sign_negative();
+        let mut x = x.abs();
+
+        if x >= 7.378_697_629_483_820_6e19
+        {
+            return if negative { -FRAC_PI_2 } else { FRAC_PI_2 };
+        }
+
+        // Shifts the input close to zero, remembering which known angle it was shifted from
+        let known = if x < 0.4375
+        {
+            if x < 7.450_580_596_923_828e-9 { return if negative { -x } else { x }; }
+            None
+        }
+        else if x < 0.6875 { x = (2.0 * x - 1.0) / (2.0 + x); Some(0) }
+        else if x < 1.1875 { x = (x - 1.0) / (x + 1.0); Some(1) }
+        else if x < 2.4375 { x = (x - 1.5) / (1.0 + 1.5 * x); Some(2) }
+        else { x = -1.0 / x; Some(3) };
+
+        let z = x * x;
+        let w = z * z;
+        let s1 = z * (AT[0] + w * (AT[2] + w * (AT[4] + w * (AT[6] + w * (AT[8] + w * AT[10])))));
+        let s2 = w * (AT[1] + w * (AT[3] + w * (AT[5] + w * (AT[7] + w * AT[9]))));
+
+        let out = match known
+        {
+            None => x - x * (s1 + s2),
+            Some(id) => ATAN_HI[id] - ((x * (s1 + s2) - ATAN_LO[id]) - x),
+        };
+
+        if negative { -out } else { out }
+    }
+
+    const LN2_HI: f64 = 6.931_471_803_691_238_164_90e-01;
+    const LN2_LO: f64 = 1.908_214_929_270_587_700_02e-10;
+
+    fn exp(x: f64) -> f64
+    {
+        const P1: f64 = 1.666_666_666_666_660_190_37e-01;
+        const P2: f64 = -2.777_777_777_701_559_338_42e-03;
+        const P3: f64 = 6.613_756_321_437_934_361_17e-05;
+        const P4: f64 = -1.653_390_220_546_525_153_90e-06;
+        const P5: f64 = 4.138_136_797_057_238_460_39e-08;
+
+        if x.is_nan()
+        {
+            return x;
+        }
+
+        if x > 709.782_712_893_384
+        {
+            return f64::INFINITY;
+        }
+
+        if x < -745.133_219_101_941_1
+        {
+            return 0.0;
+        }
+
+        // Splits off a power of two, leaving a small remainder for the polynomial
+        let k = round(x * core::f64::consts::LOG2_E);
+        let hi = x - k * LN2_HI;
+        let lo = k * LN2_LO;
+        let r = hi - lo;
+
+        let t = r * r;
+        let c = r - t * (P1 + t * (P2 + t * (P3 + t * (P4 + t * P5))));
+        let y = 1.0 - ((lo - (r * c) / (2.0 - c)) - hi);
+
+        scale_by_power_of_two(y, k as i32)
+    }
+
+    fn ln(x: f64) -> f64
+    {
+        const LG1: f64 = 6.666_666_666_666_735_130e-01;
+        const LG2: f64 = 3.999_999_999_940_941_908e-01;
+        const LG3: f64 = 2.857_142_874_366_239_149e-01;
+        const LG4: f64 = 2.222_219_843_214_978_396e-01;
+        const LG5: f64 = 1.818_357_216_161_805_012e-01;
+        const LG6: f64 = 1.531_383_769_920_937_332e-01;
+        const LG7: f64 = 1.479_819_860_511_658_591e-01;
+
+        if x.is_nan() || x < 0.0
+        {
+            return f64::NAN;
+        }
+
+        if x == 0.0
+        {
+            return f64::NEG_INFINITY;
+        }
+
+        if x.is_infinite()
+        {
+            return x;
+        }
+
+        // Subnormals are scaled up so they have a normal exponent to split off
+        let (x, mut k) = if x < f64::MIN_POSITIVE { (x * 18_014_398_509_481_984.0, -54) } else { (x, 0) };
+
+        // Splits off the exponent, picking it so the rest is between sqrt(2)/2 and sqrt(2)
+        let bits = x.to_bits();
+        let high = ((bits >> 32) as u32) + (0x3ff0_0000 - 0x3fe6_a09e);
+        k += (high >> 20) as i32 - 0x3ff;
+        let high = (high & 0x000f_ffff) + 0x3fe6_a09e;
+        let x = f64::from_bits((u64::from(high) << 32) | (bits & 0xffff_ffff));
+
+        let f = x - 1.0;
+        let half_square = 0.5 * f * f;
+        let s = f / (2.0 + f);
+        let z = s * s;
+        let w = z * z;
+        let t1 = w * (LG2 + w * (LG4 + w * LG6));
+        let t2 = z * (LG1 + w * (LG3 + w * (LG5 + w * LG7)));
+        let dk = f64::from(k);
+
+        s * (half_square + t1 + t2) + dk * LN2_LO - half_square + f + dk * LN2_HI
+    }
+
+    fn scale_by_power_of_two(x: f64, power: i32) -> f64
+    {
+        let two_to = |power: i32| f64::from_bits(((power + 0x3ff) as u64) << 52);
+
+        if power > 1023 {
+            x * two_to(1023) * two_to(power - 1023)
+        } else if power < -1022 {
+            x * two_to(-1022) * two_to(power + 1022)
+        } else {
+            x * two_to(power)
+        }
+    }
+}
@@ -1,16 +1,44 @@
-#[cfg(test)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(all(test, feature = "std"))]
 mod tests;
+#[cfg(test)]
+mod core_tests;
 
 #[macro_use]
 mod utils;
+mod float_math;
 
-mod number;
-mod traits;
+/// The generic `YololNumber<T>` and everything attached to it. Most of the time you'll
+/// want the `YololNumber` typedef instead, which is backed by an `i128`.
+pub mod number;
+/// The traits bounding what types can back a `YololNumber<T>`, or be used as arguments to one.
+pub mod traits;
+/// Operations over whole slices of `YololNumber`s, for when the same math runs across many numbers at once.
+/// Adding and subtracting slices is faster than a loop with the operators, the rest is for convenience.
+pub mod batch;
+/// Packing several small whole number fields into a single `YololNumber`, and the yolol code to do the same.
+pub mod packer;
+/// A C interface over raw inners in an `int64_t`, declared in `include/yolol_number.h`.
+pub mod ffi;
 
 /// A typedef for a `YololNumber` backed by the defacto standard: an i128.
 pub type YololNumber = number::YololNumber<i128>;
+/// A typedef for a `Shadowed` tracking the standard `YololNumber`.
+pub type Shadowed = number::shadowed::Shadowed<i128>;
+/// A typedef for a `YololInterval` of the standard `YololNumber`.
+pub type YololInterval = number::interval::YololInterval<i128>;
+/// A typedef for an `AtomicYololNumber` holding the standard `YololNumber`.
+#[cfg(target_has_atomic = "64")]
+pub type AtomicYololNumber = number::atomic::AtomicYololNumber<i128>;
 
 pub use number::conversions::from_str::error::FromStrError;
+pub use number::conversions::error::ConversionError;
+pub use number::conversions::radix::error::FromStrRadixError;
+pub use number::raw_inner::RawInner;
+pub use number::semantics::Semantics;
+pub use number::report::OpReport;
+pub use packer::PackError;
 
 /// Import this to get the standard `YololNumber` typedef and all the traits
 /// you need to perform all the operations that you could want.
@@ -18,10 +46,22 @@ pub mod prelude
 {
     pub use crate::traits::YololOps;
     pub use crate::YololNumber;
+    pub use crate::RawInner;
+    pub use crate::Semantics;
+    pub use crate::OpReport;
+    pub use crate::Shadowed;
+    pub use crate::YololInterval;
+    #[cfg(target_has_atomic = "64")]
+    pub use crate::AtomicYololNumber;
+    pub use crate::yolol;
 
     pub use crate::FromStrError;
+    pub use crate::ConversionError;
+    pub use crate::FromStrRadixError;
+    pub use crate::PackError;
 
     pub use num_traits::{
+        Num,
         One,
         Zero,
 
@@ -36,6 +76,23 @@ pub mod prelude
         CheckedMul,
         CheckedDiv,
         CheckedRem,
+        CheckedNeg,
+
+        Saturating,
+        SaturatingAdd,
+        SaturatingSub,
+        SaturatingMul,
+
+        WrappingAdd,
+        WrappingSub,
+        WrappingMul,
+
+        Euclid,
+        CheckedEuclid,
+
+        Pow,
+        Inv,
+        MulAdd,
     };
 }
 
@@ -0,0 +1,165 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicI64, Ordering};
+
+use super::YololNumber;
+use super::report::{self, OpKind, OpReport};
+
+use crate::traits::{YololOps, YololRange, StandardRange};
+
+/// A `YololNumber` that can be shared between threads, stored as its raw inner in an `AtomicI64`.
+/// That works because the range in the game is the range of an `i64`, so using a range that doesn't
+/// fit in an `i64` with an inner type larger than one fails to compile.
+///
+/// The arithmetic saturates exactly like the regular operators, retrying until it applies to the latest value.
+pub struct AtomicYololNumber<T: YololOps, R: YololRange = StandardRange>
+{
+    inner: AtomicI64,
+    _marker: PhantomData<(T, R)>,
+}
+
+impl<T: YololOps, R: YololRange> AtomicYololNumber<T, R>
+{
+    /// Stops compilation if the range doesn't always fit in an `i64`. The sealed inner types
+    /// no larger than an `i64` always fit, since the range is limited by the inner type.
+    const RANGE_FITS: () = assert!(
+        core::mem::size_of::<T>() <= 8 || (R::MIN_INNER >= i64::MIN as i128 && R::MAX_INNER <= i64::MAX as i128),
+        "AtomicYololNumber can only hold a range that fits in an i64!"
+    );
+
+    /// Creates a new atomic holding the number.
+    pub fn new(num: YololNumber<T, R>) -> Self
+    {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::RANGE_FITS;
+
+        AtomicYololNumber {
+            inner: AtomicI64::new(Self::to_bits(num)),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Consumes the atomic and returns the number it holds.
+    pub fn into_inner(self) -> YololNumber<T, R>
+    {
+        Self::from_bits(self.inner.into_inner())
+    }
+
+    /// Loads the number, the same as `AtomicI64::load`.
+    pub fn load(&self, order: Ordering) -> YololNumber<T, R>
+    {
+        Self::from_bits(self.inner.load(order))
+    }
+
+    /// Stores the number, the same as `AtomicI64::store`.
+    pub fn store(&self, num: YololNumber<T, R>, order: Ordering)
+    {
+        self.inner.store(Self::to_bits(num), order);
+    }
+
+    /// Stores the number and returns the previous one, the same as `AtomicI64::swap`.
+    pub fn swap(&self, num: YololNumber<T, R>, order: Ordering) -> YololNumber<T, R>
+    {
+        Self::from_bits(self.inner.swap(Self::to_bits(num), order))
+    }
+
+    /// Stores `new` if the current number is `current`, the same as `AtomicI64::compare_exchange`.
+    /// Returns the previous number, which is in `Ok` if it was replaced.
+    pub fn compare_exchange(
+        &self,
+        current: YololNumber<T, R>,
+        new: YololNumber<T, R>,
+        success: Ordering,
+        failure: Ordering
+    ) -> Result<YololNumber<T, R>, YololNumber<T, R>>
+    {
+        self.inner.compare_exchange(Self::to_bits(current), Self::to_bits(new), success, failure)
+            .map(Self::from_bits)
+            .map_err(Self::from_bits)
+    }
+
+    /// Adds to the number the same as the `+` operator, saturating at the bounds, and returns the previous number.
+    pub fn fetch_add(&self, num: YololNumber<T, R>, order: Ordering) -> YololNumber<T, R>
+    {
+        self.fetch_apply(OpKind::Add, order, |current| current.add_with_report(num))
+    }
+
+    /// Subtracts from the number the same as the `-` operator, saturating at the bounds, and returns the previous number.
+    pub fn fetch_sub(&self, num: YololNumber<T, R>, order: Ordering) -> YololNumber<T, R>
+    {
+        self.fetch_apply(OpKind::Sub, order, |current| current.sub_with_report(num))
+    }
+
+    /// Applies the operation in a compare and exchange loop until it sticks, then tells the observer about
+    /// the report from the attempt that stuck. That way retries don't get reported more than once.
+    fn fetch_apply<F>(&self, kind: OpKind, order: Ordering, op: F) -> YololNumber<T, R>
+    where F: Fn(YololNumber<T, R>) -> (YololNumber<T, R>, OpReport)
+    {
+        let mut current = self.inner.load(Ordering::Relaxed);
+
+        loop
+        {
+            let (new, op_report) = op(Self::from_bits(current));
+
+            match self.inner.compare_exchange_weak(current, Self::to_bits(new), order, failure_ordering(order))
+            {
+                Ok(previous) => {
+                    report::notify(kind, op_report);
+                    return Self::from_bits(previous);
+                },
+
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    fn to_bits(num: YololNumber<T, R>) -> i64
+    {
+        num.0.to_i64()
+            .expect("[AtomicYololNumber::to_bits] Number doesn't fit in an i64! The range check is somehow broken :(")
+    }
+
+    fn from_bits(bits: i64) -> YololNumber<T, R>
+    {
+        // Only numbers that were already valid get stored, so they always fit back in the inner type
+        let inner = T::from(bits)
+            .expect("[AtomicYololNumber::from_bits] Stored number doesn't fit in the inner type! The crate is somehow broken :(");
+
+        YololNumber(inner, PhantomData)
+    }
+}
+
+/// The strongest ordering a failed compare and exchange can use, given the ordering for the whole operation.
+fn failure_ordering(order: Ordering) -> Ordering
+{
+    match order
+    {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        order => order,
+    }
+}
+
+impl<T: YololOps, R: YololRange> Default for AtomicYololNumber<T, R>
+{
+    fn default() -> Self
+    {
+        Self::new(YololNumber::default())
+    }
+}
+
+impl<T: YololOps, R: YololRange> From<YololNumber<T, R>> for AtomicYololNumber<T, R>
+{
+    fn from(num: YololNumber<T, R>) -> Self
+    {
+        Self::new(num)
+    }
+}
+
+impl<T: YololOps, R: YololRange> fmt::Debug for AtomicYololNumber<T, R>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
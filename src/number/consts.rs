@@ -0,0 +1,201 @@
+use core::marker::PhantomData;
+
+use super::{YololNumber, NUMBER_OF_PLACES};
+
+use crate::traits::YololRange;
+
+/// Creates a `YololNumber` from a number or string literal, checked and scaled at compile time.
+/// `yolol!(3.141)`, `yolol!(-2)` and `yolol!("-0.5")` all work, and can be used to initialize a `const`.
+///
+/// Fails to compile if the literal isn't a number, has non-zero digits past the third
+/// decimal place, or is out of range.
+#[macro_export]
+macro_rules! yolol {
+    ( - $literal:literal ) => {{
+        const NUMBER: $crate::YololNumber = $crate::YololNumber::__from_literal(concat!("-", stringify!($literal)));
+        NUMBER
+    }};
+
+    ( $literal:literal ) => {{
+        const NUMBER: $crate::YololNumber = $crate::YololNumber::__from_literal(stringify!($literal));
+        NUMBER
+    }};
+}
+
+/// The conversion factor, as a raw inner. Spelled out here since the generic version can't be used in a const.
+const CONVERSION_VAL: i128 = 10_i128.pow(NUMBER_OF_PLACES as u32);
+
+/// Implements the const constructors and constants for each of the inner types,
+/// since the generic conversions can't be used in a const.
+macro_rules! impl_consts {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl<R: YololRange> YololNumber<$t, R>
+            {
+                /// The raw inner of the largest expressible value, the same as `max_value`.
+                const MAX_INNER: i128 = if R::MAX_INNER > <$t>::MAX as i128 { <$t>::MAX as i128 } else { R::MAX_INNER };
+                /// The raw inner of the smallest expressible value, the same as `min_value`.
+                const MIN_INNER: i128 = if R::MIN_INNER < <$t>::MIN as i128 { <$t>::MIN as i128 } else { R::MIN_INNER };
+
+                /// Zero.
+                pub const ZERO: Self = Self::from_inner_const(0);
+                /// One, or the largest value if one is out of range.
+                pub const ONE: Self = Self::from_inner_const(CONVERSION_VAL);
+                /// The largest expressible value, the same as `max_value`.
+                pub const MAX: Self = Self::from_inner_const(Self::MAX_INNER);
+                /// The smallest expressible value, the same as `min_value`.
+                pub const MIN: Self = Self::from_inner_const(Self::MIN_INNER);
+                /// Pi, rounded to `3.142`.
+                pub const PI: Self = Self::from_inner_const(3_142);
+                /// Euler's number, rounded to `2.718`.
+                pub const E: Self = Self::from_inner_const(2_718);
+
+                /// Creates a `YololNumber` with the input directly used as the raw inner, usable in a const.
+                /// Saturates at the bounds of a `YololNumber`, the same as `from_raw`.
+                pub const fn from_inner_const(inner: i128) -> Self
+                {
+                    let inner = if inner > Self::MAX_INNER
+                    {
+                        Self::MAX_INNER
+                    }
+                    else if inner < Self::MIN_INNER
+                    {
+                        Self::MIN_INNER
+                    }
+                    else
+                    {
+                        inner
+                    };
+
+                    // The bounds fit in the inner type, so this can't truncate
+                    YololNumber(inner as $t, PhantomData)
+                }
+
+                /// Parses a `YololNumber` the same as `FromStr`, usable in a const.
+                /// Panics if the input can't be parsed, has non-zero digits past the
+                /// third decimal place, or is out of range. In a const that fails compilation instead.
+                pub const fn from_str_const(string: &str) -> Self
+                {
+                    Self::from_inner_const(parse_const(string, false, Self::MIN_INNER, Self::MAX_INNER))
+                }
+
+                /// The parser behind `yolol!`, which also accepts the quotes of a string
+                /// literal and the underscores of a number literal.
+                #[doc(hidden)]
+                pub const fn __from_literal(literal: &str) -> Self
+                {
+                    Self::from_inner_const(parse_const(literal, true, Self::MIN_INNER, Self::MAX_INNER))
+                }
+            }
+        )*
+    }
+}
+
+impl_consts!(i32, i64, i128);
+
+/// Parses a number string into a raw inner, panicking if it isn't valid or doesn't fit between the bounds.
+/// Accepts the same strings as `FromStr`, except digits past the decimal places have to be zero.
+const fn parse_const(string: &str, literal: bool, min: i128, max: i128) -> i128
+{
+    let bytes = string.as_bytes();
+    let mut start = 0;
+    let mut end = bytes.len();
+
+    if literal && end >= 2 && bytes[0] == b'"' && bytes[end - 1] == b'"'
+    {
+        start += 1;
+        end -= 1;
+    }
+
+    let negative = start < end && bytes[start] == b'-';
+    if start < end && (bytes[start] == b'-' || bytes[start] == b'+')
+    {
+        start += 1;
+    }
+
+    // The magnitude is built up as a negative number so the bottom of the range doesn't overflow
+    let mut inner: i128 = 0;
+    let mut main_digits = 0;
+    // How many decimal digits have been seen, or `None` before the decimal point
+    let mut places: Option<u8> = None;
+
+    let mut index = start;
+    while index < end
+    {
+        let byte = bytes[index];
+        index += 1;
+
+        match (byte, places)
+        {
+            (b'0'..=b'9', Some(seen)) if seen >= NUMBER_OF_PLACES => {
+                if byte != b'0'
+                {
+                    panic!("YololNumber literal has non-zero digits past the third decimal place");
+                }
+            },
+
+            (b'0'..=b'9', _) => {
+                inner = match inner.checked_mul(10)
+                {
+                    Some(shifted) => match shifted.checked_sub((byte - b'0') as i128)
+                    {
+                        Some(inner) => inner,
+                        None => panic!("YololNumber literal is out of range"),
+                    },
+                    None => panic!("YololNumber literal is out of range"),
+                };
+
+                places = match places
+                {
+                    Some(seen) => Some(seen + 1),
+                    None => {
+                        main_digits += 1;
+                        None
+                    },
+                };
+            },
+
+            (b'.', None) if main_digits > 0 => places = Some(0),
+            (b'_', _) if literal && index - 1 > start && bytes[index - 2] != b'_' => {},
+
+            _ => panic!("YololNumber literal isn't a number"),
+        }
+    }
+
+    if main_digits == 0
+    {
+        panic!("YololNumber literal isn't a number");
+    }
+
+    // Shift up to the full number of places
+    let mut seen = match places { Some(seen) => seen, None => 0 };
+    while seen < NUMBER_OF_PLACES
+    {
+        inner = match inner.checked_mul(10)
+        {
+            Some(inner) => inner,
+            None => panic!("YololNumber literal is out of range"),
+        };
+        seen += 1;
+    }
+
+    let inner = if negative
+    {
+        inner
+    }
+    else
+    {
+        match inner.checked_neg()
+        {
+            Some(inner) => inner,
+            None => panic!("YololNumber literal is out of range"),
+        }
+    };
+
+    if inner < min || inner > max
+    {
+        panic!("YololNumber literal is out of range");
+    }
+
+    inner
+}
@@ -1,10 +1,17 @@
 use super::YololNumber;
+use super::raw_inner::RawInner;
 
-use crate::traits::YololOps;
+use num_traits::AsPrimitive;
 
+use crate::float_math;
+use crate::traits::{YololOps, YololRange};
+
+pub mod error;
 pub mod from_str;
+pub mod radix;
+mod primitive;
 
-impl<T: YololOps> From<bool> for YololNumber<T>
+impl<T: YololOps, R: YololRange> From<bool> for YololNumber<T, R>
 {
     // Clippy doesn't like using a match for this,
     // but it's the most expressive for the situation.
@@ -14,44 +21,149 @@ impl<T: YololOps> From<bool> for YololNumber<T>
     }
 }
 
-impl<T: YololOps> std::fmt::Display for YololNumber<T>
+/// Enough room to format a number with any of the sealed inner types, so `Display`
+/// and serialization can format on the stack.
+pub(crate) const FORMAT_BUFFER_LEN: usize = 48;
+
+impl<T: YololOps, R: YololRange> YololNumber<T, R>
+{
+    /// The most bytes `format_into` can write for this inner type: a sign, every digit the inner type can hold, and a decimal point.
+    pub const MAX_FORMATTED_LEN: usize = core::mem::size_of::<T>() * 8 * 30_103 / 100_000 + 3;
+
+    /// Formats the number into the given buffer without allocating, returning the part of the buffer written to.
+    /// The output is the same as `Display`. Panics if the buffer is too small to fit the number,
+    /// which never happens for a buffer of at least `MAX_FORMATTED_LEN` bytes.
+    pub fn format_into<'a, const N: usize>(&self, buf: &'a mut [u8; N]) -> &'a str
+    {
+        let ten = T::from(10).expect("[YololNumber::format_into] Inner type is unable to express 10! Pick a better inner type...");
+
+        // Splitting before taking the absolute value means this can't overflow at the bottom of the range
+        let mut main_digits = (self.0 / Self::conversion_val()).abs();
+        let mut decimals = (self.0 % Self::conversion_val()).abs();
+
+        // The digits are written from the back of the buffer, so the output ends up at the end of it
+        let mut start = N;
+        let mut push = |byte: u8| {
+            start = start.checked_sub(1)
+                .expect("[YololNumber::format_into] Buffer is too small for the number! Use at least MAX_FORMATTED_LEN bytes...");
+            buf[start] = byte;
+        };
+
+        let digit = |value: T| b'0' + (value % ten).to_u8().unwrap_or(0);
+
+        if !decimals.is_zero()
+        {
+            // Trailing zeros in the decimals are never shown
+            let mut places = Self::num_places::<usize>();
+            while (decimals % ten).is_zero()
+            {
+                decimals = decimals / ten;
+                places -= 1;
+            }
+
+            for _ in 0..places
+            {
+                push(digit(decimals));
+                decimals = decimals / ten;
+            }
+
+            push(b'.');
+        }
+
+        loop
+        {
+            push(digit(main_digits));
+            main_digits = main_digits / ten;
+
+            if main_digits.is_zero() { break }
+        }
+
+        if self.0.is_negative()
+        {
+            push(b'-');
+        }
+
+        core::str::from_utf8(&buf[start..])
+            .expect("[YololNumber::format_into] Formatted number isn't valid utf8! The crate is somehow broken :(")
+    }
+}
+
+// Checking for powers needs the same float math as `pow`, so this has the same bounds
+impl<T: YololOps + AsPrimitive<f64>, R: YololRange> YololNumber<T, R>
+where f64: AsPrimitive<T>
 {
-    // Clippy screams about `if_not_else` as it reduces readability, but the
-    // alternative is actually much more unreadable in this context.
-    #[allow(clippy::if_not_else)]
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result
-    {
-        let sign_str = if self.0.signum() == -T::one() { "-" } else { "" };
-
-        // This is hacky due to overflow/underflow behaviour, fix eventually
-        let positive_inner = self.0.abs();
-        let main_digits = positive_inner / Self::conversion_val();
-
-        let ten = T::from(10).expect("[<YololNumber as Display>::fmt] Inner type is unable to express 10! Pick a better inner type...");
-        let hundred = T::from(100).expect("[<YololNumber as Display>::fmt] Inner type is unable to express 100! Pick a better inner type...");
-
-        let ones = positive_inner % ten;
-        let tens = (positive_inner / ten) % ten;
-        let hundreds = (positive_inner / hundred) % ten;
-
-        write!(f, "{}", sign_str)?;
-        write!(f, "{}", main_digits)?;
-        
-        if ones != T::zero() {
-            write!(f, ".{}{}{}", hundreds, tens, ones)
-        } else if tens != T::zero() {
-            write!(f, ".{}{}", hundreds, tens)
-        } else if hundreds != T::zero() {
-            write!(f, ".{}", hundreds)
-        } else {
-            Ok(())
+    /// Returns the shortest source text the game evaluates to exactly this number, for fitting more on a line.
+    /// Yolol needs a digit before the decimal point and has no exponents, so the candidates are the decimal form
+    /// with no leading or trailing zeros, and for whole numbers any power `b^e` that comes out the same through `pow`.
+    /// So `1000000000` becomes `10^9` and `65536` becomes `4^8`. Anything that isn't a power parses back with `FromStr`.
+    ///
+    /// Every result is a single operand, except that a power needs brackets as the right side of another `^`.
+    /// The game reads a leading `-` as negating the literal after it, so `MIN` comes out as `-9223372036854775.808`,
+    /// which `FromStr` parses back but the game saturates before negating. Write `-9223372036854775.807-0.001` for that.
+    #[cfg(feature = "std")]
+    pub fn to_shortest_literal(&self) -> String
+    {
+        let mut buf = [0_u8; FORMAT_BUFFER_LEN];
+        let decimal = self.format_into(&mut buf);
+
+        self.power_literal(decimal.len())
+            .unwrap_or_else(|| String::from(decimal))
+    }
+
+    /// Finds the shortest `b^e` that `pow` turns into exactly this number, if it's a whole number above one
+    /// and there's one shorter than `max_len`. Only the winning candidate gets formatted.
+    #[cfg(feature = "std")]
+    fn power_literal(&self, max_len: usize) -> Option<String>
+    {
+        let conversion = Self::conversion_val::<T>();
+        if self.0 <= conversion || !(self.0 % conversion).is_zero()
+        {
+            return None;
+        }
+
+        let decimal_len = |num: u64| num.checked_ilog10().unwrap_or(0) as usize + 1;
+
+        let value = self.float_value();
+        let mut shortest: Option<(usize, u64, u32)> = None;
+
+        // Past 2^63 every base overflows, and rounding the root might land either side of the real base
+        for exponent in 2..64_u32
+        {
+            let root = float_math::powf(value, 1.0 / f64::from(exponent)).round();
+
+            for base in [root - 1.0, root, root + 1.0]
+            {
+                if base < 2.0 { continue }
+
+                let len = decimal_len(base as u64) + 1 + decimal_len(u64::from(exponent));
+                if len >= shortest.map_or(max_len, |(shortest_len, _, _)| shortest_len) { continue }
+
+                // The same float math `pow` does, without telling the observer about every rejected candidate
+                let inner: T = float_math::round(float_math::powf(base, f64::from(exponent)) * Self::conversion_val::<f64>()).as_();
+
+                if inner == self.0
+                {
+                    shortest = Some((len, base as u64, exponent));
+                }
+            }
         }
+
+        shortest.map(|(_, base, exponent)| format!("{}^{}", base, exponent))
+    }
+}
+
+impl<T: YololOps, R: YololRange> core::fmt::Display for YololNumber<T, R>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result
+    {
+        let mut buf = [0_u8; FORMAT_BUFFER_LEN];
+        f.write_str(self.format_into(&mut buf))
     }
 }
 
 // Why in gods name is a reflexive blanket implementation not a thing...
 // This has been such a pain. Screw you num_traits
-impl<T: YololOps> num_traits::AsPrimitive<Self> for YololNumber<T>
+impl<T: YololOps, R: YololRange> num_traits::AsPrimitive<Self> for YololNumber<T, R>
 {
     fn as_(self) -> Self
     {
@@ -59,51 +171,100 @@ impl<T: YololOps> num_traits::AsPrimitive<Self> for YololNumber<T>
     }
 }
 
-impl<T: YololOps> num_traits::FromPrimitive for YololNumber<T>
-where
-    i64: num_traits::AsPrimitive<T>,
-    u64: num_traits::AsPrimitive<T>
+// All of the primitive traits use value semantics, so converting `5` gets you a `YololNumber`
+// that displays as `5`. Use a `RawInner` if you want to convert the raw inner instead.
+
+impl<T: YololOps, R: YololRange> num_traits::FromPrimitive for YololNumber<T, R>
 {
-    /// Treats the inputs as if it were a raw inner value.
-    /// This means it should be larger by a factor of the conversion value than the value you want.
+    /// Creates a `YololNumber` with the same value as the input, failing if it's out of range.
     fn from_i64(num: i64) -> Option<Self>
     {
-        Self::try_to_inner(num)
+        <Self as num_traits::NumCast>::from(num)
     }
 
-    /// Treats the inputs as if it were a raw inner value.
-    /// This means it should be larger by a factor of the conversion value than the value you want.
+    /// Creates a `YololNumber` with the same value as the input, failing if it's out of range.
     fn from_u64(num: u64) -> Option<Self>
     {
-        Self::try_to_inner(num)
+        <Self as num_traits::NumCast>::from(num)
+    }
+
+    /// Creates a `YololNumber` with the same value as the input, failing if it's out of range.
+    fn from_i128(num: i128) -> Option<Self>
+    {
+        <Self as num_traits::NumCast>::from(num)
+    }
+
+    /// Creates a `YololNumber` with the same value as the input, failing if it's out of range.
+    fn from_u128(num: u128) -> Option<Self>
+    {
+        <Self as num_traits::NumCast>::from(num)
+    }
+
+    /// Creates a `YololNumber` with the same value as the input, rounding any decimals
+    /// past what a `YololNumber` can express. Fails if it's NaN or out of range.
+    fn from_f64(num: f64) -> Option<Self>
+    {
+        <Self as num_traits::NumCast>::from(num)
     }
 }
 
-impl<T: YololOps> num_traits::ToPrimitive for YololNumber<T>
+impl<T: YololOps, R: YololRange> num_traits::ToPrimitive for YololNumber<T, R>
 {
-    /// Directly outputs the raw inner value, does not scale it.
+    /// Outputs the value with any decimals truncated, the same as a float would.
     fn to_i64(&self) -> Option<i64>
     {
-        self.try_from_inner()
+        (self.0 / Self::conversion_val::<T>()).to_i64()
     }
 
-    /// Directly outputs the raw inner value, does not scale it.
+    /// Outputs the value with any decimals truncated, the same as a float would.
     fn to_u64(&self) -> Option<u64>
     {
-        self.try_from_inner()
+        (self.0 / Self::conversion_val::<T>()).to_u64()
+    }
+
+    /// Outputs the value with any decimals truncated, the same as a float would.
+    fn to_i128(&self) -> Option<i128>
+    {
+        (self.0 / Self::conversion_val::<T>()).to_i128()
+    }
+
+    /// Outputs the value with any decimals truncated, the same as a float would.
+    fn to_u128(&self) -> Option<u128>
+    {
+        (self.0 / Self::conversion_val::<T>()).to_u128()
+    }
+
+    /// Outputs the value, including its decimals.
+    fn to_f64(&self) -> Option<f64>
+    {
+        let conversion: f64 = num_traits::NumCast::from(Self::conversion_val::<T>())?;
+        Some(self.0.to_f64()? / conversion)
     }
 }
 
-impl<T: YololOps> num_traits::NumCast for YololNumber<T>
+impl<T: YololOps, R: YololRange> num_traits::NumCast for YololNumber<T, R>
 {
-    /// Treats the inputs as if it were a raw inner value.
-    /// This means it should be larger by a factor of the conversion value than the value you want.
+    /// Creates a `YololNumber` with the same value as the input. Decimals past what
+    /// a `YololNumber` can express are rounded, and out of range values fail.
     fn from<F>(input: F) -> Option<Self>
     where
         F: num_traits::ToPrimitive
     {
-        let raw_inner = T::from(input)?;
-        Some(YololNumber(raw_inner))
+        let float = input.to_f64()?;
+
+        // Whole numbers skip going through a float so large values don't lose precision
+        if float_math::fract(float) == 0.0
+        {
+            if let Some(int) = input.to_i128()
+            {
+                let value = T::from(int)?;
+                return Self::try_from_value(value).ok();
+            }
+        }
+
+        let conversion: f64 = num_traits::NumCast::from(Self::conversion_val::<T>())?;
+        let inner = T::from(float_math::round(float * conversion))?;
+
+        Self::try_from_raw(RawInner(inner)).ok()
     }
 }
-
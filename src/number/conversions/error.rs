@@ -0,0 +1,55 @@
+use core::fmt;
+
+/// The ways a checked conversion into or out of a `YololNumber` can fail.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConversionError
+{
+    /// The input is larger than the largest expressible `YololNumber`.
+    Overflow,
+    /// The input is smaller than the smallest expressible `YololNumber`.
+    Underflow,
+
+    /// The input would lose information (such as decimal digits)
+    /// when converted into the Yolol number backing type.
+    Truncated,
+
+    /// The input was a float NaN, which has no equivalent `YololNumber`.
+    NotANumber,
+}
+
+impl fmt::Display for ConversionError
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        match self
+        {
+            ConversionError::Overflow => f.write_str("Input is larger than the maximum value expressible in a Yolol number."),
+            ConversionError::Underflow => f.write_str("Input is smaller than the minimum value expressible in a Yolol number."),
+            ConversionError::Truncated => f.write_str("Input can't be converted into the Yolol number backing type without losing information."),
+            ConversionError::NotANumber => f.write_str("Input is NaN, which can't be expressed as a Yolol number."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+impl ConversionError
+{
+    /// Returns the error for a value outside of the expressible range,
+    /// picking the direction based on the sign of the value.
+    pub(crate) fn out_of_range(negative: bool) -> Self
+    {
+        if negative { ConversionError::Underflow } else { ConversionError::Overflow }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<ConversionError> for String
+{
+    fn from(input: ConversionError) -> Self
+    {
+        input.to_string()
+    }
+}
@@ -1,68 +1,163 @@
-use std::str::FromStr;
+use core::str::FromStr;
 
+#[cfg(feature = "std")]
 use regex::Regex;
+#[cfg(feature = "std")]
 use lazy_static::lazy_static;
 
 use super::YololNumber;
 
-use crate::traits::YololOps;
+use crate::number::report::{self, OpKind, OpReport};
+use crate::traits::{YololOps, YololRange};
 
 pub mod error;
 use error::FromStrError as Error;
 
+#[cfg(feature = "std")]
 static YOLOL_NUM_MATCHER_REGEX: &str = r"^(?P<sign>\+|-)?(?P<main>[0-9]+)(?:\.(?P<dec_zero>0*)(?P<dec_num>[0-9]*))?$";
 
+#[cfg(feature = "std")]
 lazy_static! {
     static ref YOLOL_NUM_MATCHER: Regex = Regex::new(YOLOL_NUM_MATCHER_REGEX)
         .expect("Unable to compile YololNumber::from_str regex! The crate is somehow broken :(");
 }
 
-impl<T: YololOps> FromStr for YololNumber<T>
+/// The important sections of a number string, named the same as the groups in the regex.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Parts<'a>
+{
+    pub sign: Option<&'a str>,
+    pub main: Option<&'a str>,
+    pub dec_zero: Option<&'a str>,
+    pub dec_num: Option<&'a str>,
+}
+
+/// Runs the input through our regex. Verifies structure and gives captures for important sections
+#[cfg(feature = "std")]
+pub(crate) fn match_parts(string: &str) -> Option<Parts<'_>>
+{
+    let captures = YOLOL_NUM_MATCHER.captures(string)?;
+    let group = |name| captures.name(name).map(|m| m.as_str());
+
+    Some(Parts {
+        sign: group("sign"),
+        main: group("main"),
+        dec_zero: group("dec_zero"),
+        dec_num: group("dec_num"),
+    })
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) use split_parts as match_parts;
+
+/// Splits up the input by hand, matching exactly what the regex would, for when regex isn't available.
+#[cfg(any(test, not(feature = "std")))]
+pub(crate) fn split_parts(string: &str) -> Option<Parts<'_>>
+{
+    let is_digit = |c: char| c.is_ascii_digit();
+
+    let (sign, rest) = match string.as_bytes().first()
+    {
+        Some(b'+') | Some(b'-') => (Some(&string[..1]), &string[1..]),
+        _ => (None, string),
+    };
+
+    let main_len = rest.find(|c: char| !is_digit(c)).unwrap_or(rest.len());
+    if main_len == 0
+    {
+        return None;
+    }
+
+    let (main, rest) = rest.split_at(main_len);
+
+    let (dec_zero, dec_num) = match rest.strip_prefix('.')
+    {
+        None if rest.is_empty() => (None, None),
+        None => return None,
+
+        Some(decimals) if decimals.chars().all(is_digit) => {
+            let zeros = decimals.len() - decimals.trim_start_matches('0').len();
+            (Some(&decimals[..zeros]), Some(&decimals[zeros..]))
+        },
+        Some(_) => return None,
+    };
+
+    Some(Parts { sign, main: Some(main), dec_zero, dec_num })
+}
+
+impl<T: YololOps, R: YololRange> FromStr for YololNumber<T, R>
 {
     type Err = Error;
 
     fn from_str(string: &str) -> Result<Self, Self::Err>
     {
-        // Runs the input through our regex. Verifies structure and
-        // gives captures for important sections
-        let captures = YOLOL_NUM_MATCHER.captures(string)
-            .ok_or_else(|| Error::InputVerificationFailure)?;
+        Self::parse_reporting(string)
+            .map(|(num, _)| num)
+    }
+}
+
+impl<T: YololOps, R: YololRange> YololNumber<T, R>
+{
+    /// Parses a `YololNumber` the same as `FromStr`, reporting if the result
+    /// saturated or had decimal digits past the third place dropped.
+    pub fn parse_reporting(string: &str) -> Result<(Self, OpReport), Error>
+    {
+        let output = Self::parse_with_report(string)?;
+        Ok(report::observe(OpKind::Parse, output))
+    }
+
+    fn parse_with_report(string: &str) -> Result<(Self, OpReport), Error>
+    {
+        let parts = match_parts(string)
+            .ok_or(Error::InputVerificationFailure)?;
 
         // Gets the number of leading zeroes in the decimal digits
-        let dec_zeros = captures.name("dec_zero")
-            .map_or(0, |m| m.as_str().len());
+        let dec_zeros = parts.dec_zero
+            .map_or(0, |m| m.len());
+
+        // Any non-zero digit past the number of places gets dropped, which is worth reporting
+        let truncated = parts.dec_num
+            .is_some_and(|m| {
+                let kept = Self::num_places::<usize>().saturating_sub(dec_zeros);
+                m.chars().skip(kept).any(|digit| digit != '0')
+            });
 
         // Converts the sign capture group into the equivalent number.
         // Basically signum but from a string
-        let sign_num = match captures.name("sign")
+        let sign_num = match parts.sign
         {
             None => T::one(),
 
-            Some(sign) if sign.as_str() == "+" => T::one(),
-            Some(sign) if sign.as_str() == "-" => -T::one(),
+            Some("+") => T::one(),
+            Some("-") => -T::one(),
 
             Some(_) => return Err(Error::InvalidSignMatched)
         };
 
-        // Parses all the digits before the decimal point into a number
-        let main_num = match captures.name("main")
+        // Parses all the digits before the decimal point into a number.
+        // They're known to be digits, so failing to parse means they're too long for the backing type,
+        // which is past the range of any `YololNumber` using it and saturates the same as any other large input
+        let main_num = match parts.main
         {
-            Some(num) => num.as_str().parse::<T>()
-                .map_err(|_| Error::MainDigitsParseError)?,
+            Some(num) => match num.parse::<T>()
+            {
+                Ok(num) => num,
+                Err(_) => return Ok(Self::saturate(sign_num.is_positive())),
+            },
 
             None => return Err(Error::NoMainDigits)
         };
 
         // Parses the digits after the decimal point into the correct number.
         // This is the most annoying part of parsing a YololNumber from a string...
-        let decimal_num = match captures.name("dec_num")
+        let decimal_num = match parts.dec_num
         {
             // If the number of zeros at the start of the decimal digits is >= the number of decimal places
             // then we're out of digits of precision and just want a 0 (aka, no decimal).
             Some(_) if dec_zeros >= Self::num_places() => T::zero(),
 
             // If there are no decimal numbers, then we also just want 0
-            Some(num) if num.as_str().is_empty() => T::zero(),
+            Some("") => T::zero(),
 
             // Otherwise, we've gotta do some fancy logic to determine the correct
             // decimal number we want.
@@ -76,7 +171,7 @@ impl<T: YololOps> FromStr for YololNumber<T>
                 // Basically, if we have more digits in the slice than we need, we only want
                 // to get those we need. Otherwise, we want to get as many as we have. Logically
                 // that value should then be greater than 0 and less than or equal to the number of places.
-                let slice_len = usize::min(num.as_str().len(), nums_we_need);
+                let slice_len = usize::min(num.len(), nums_we_need);
                 if !(slice_len > 0 && slice_len <= Self::num_places()) { return Err(Error::DecimalSliceLenLogicError) }
 
                 // We know how many digits we're getting, but we still have to make sure they
@@ -95,12 +190,12 @@ impl<T: YololOps> FromStr for YololNumber<T>
                     // Thanks iterators!
                     let out = (0..shift_pow).fold(1, |a, _| a*10);
 
-                    T::from(out).ok_or_else(|| Error::ShiftConversionFailure)?
+                    T::from(out).ok_or(Error::ShiftConversionFailure)?
                 };
 
                 // Now that we know how many digits to get and how much to multiply them by,
                 // we extract said number of digits, multiply them by said power, and away we go!
-                num.as_str()[0..slice_len].parse::<T>()
+                num[0..slice_len].parse::<T>()
                     .map(|n| n * shift)
                     .map_err(|_| Error::DecimalDigitsParseError)?
             },
@@ -114,7 +209,9 @@ impl<T: YololOps> FromStr for YololNumber<T>
         let decimal_num = decimal_num * sign_num;
 
         // Finally, construct the final YololNumber!
-        YololNumber::<T>::from_split(main_num, decimal_num)
-            .ok_or_else(|| Error::FromSplitCreationFailure)
+        let (num, report) = YololNumber::<T, R>::split_with_report(main_num, decimal_num)
+            .ok_or(Error::FromSplitCreationFailure)?;
+
+        Ok((num, report | OpReport::truncation(truncated)))
     }
-}
\ No newline at end of file
+}
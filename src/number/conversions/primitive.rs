@@ -0,0 +1,142 @@
+use core::convert::TryFrom;
+
+use num_traits::{
+    AsPrimitive,
+    NumCast,
+};
+
+use super::error::ConversionError;
+use super::YololNumber;
+
+use crate::traits::{YololOps, YololRange};
+
+// All of the conversions in here use value semantics, meaning that converting `5`
+// gets you a `YololNumber` that displays as `5`, not `0.005`.
+
+impl<T: YololOps, R: YololRange> YololNumber<T, R>
+{
+    /// Converts the value into some integer type, failing if there's any decimals
+    /// or if the value doesn't fit in the output type.
+    fn try_into_int_value<F: NumCast>(self) -> Result<F, ConversionError>
+    {
+        let conversion = Self::conversion_val::<T>();
+
+        if !(self.0 % conversion).is_zero()
+        {
+            return Err(ConversionError::Truncated);
+        }
+
+        F::from(self.0 / conversion)
+            .ok_or_else(|| ConversionError::out_of_range(self.0.is_negative()))
+    }
+
+    /// Converts the value into some float type. Fails only if the backing type can't be
+    /// expressed by the float type.
+    fn try_into_float_value<F: NumCast + core::ops::Div<Output=F>>(self) -> Result<F, ConversionError>
+    {
+        let inner = F::from(self.0)
+            .ok_or_else(|| ConversionError::out_of_range(self.0.is_negative()))?;
+
+        let conversion = F::from(Self::conversion_val::<T>())
+            .ok_or(ConversionError::Truncated)?;
+
+        Ok(inner / conversion)
+    }
+}
+
+/// Implements infallible conversions for types where every value fits in a `YololNumber`.
+macro_rules! impl_from_small_int {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl<T: YololOps, R: YololRange> From<$t> for YololNumber<T, R>
+            where
+                $t: AsPrimitive<T>
+            {
+                fn from(input: $t) -> Self
+                {
+                    YololNumber::from_value(input)
+                }
+            }
+        )*
+    }
+}
+
+/// Implements fallible conversions for integer types that may not fit in a `YololNumber`.
+macro_rules! impl_try_from_large_int {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl<T: YololOps, R: YololRange> TryFrom<$t> for YololNumber<T, R>
+            where
+                $t: AsPrimitive<T>
+            {
+                type Error = ConversionError;
+
+                fn try_from(input: $t) -> Result<Self, Self::Error>
+                {
+                    YololNumber::try_from_value(input)
+                }
+            }
+        )*
+    }
+}
+
+/// Implements fallible conversions for float types, which also fail if decimals past the places of a `YololNumber`
+/// would be lost. Use `from_value` to truncate them instead.
+macro_rules! impl_try_from_float {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl<T: YololOps, R: YololRange> TryFrom<$t> for YololNumber<T, R>
+            where
+                $t: AsPrimitive<T>
+            {
+                type Error = ConversionError;
+
+                fn try_from(input: $t) -> Result<Self, Self::Error>
+                {
+                    YololNumber::try_from_value(input)
+                }
+            }
+        )*
+    }
+}
+
+/// Implements fallible conversions out of a `YololNumber` into integer types.
+macro_rules! impl_try_into_int {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl<T: YololOps, R: YololRange> TryFrom<YololNumber<T, R>> for $t
+            {
+                type Error = ConversionError;
+
+                fn try_from(input: YololNumber<T, R>) -> Result<Self, Self::Error>
+                {
+                    input.try_into_int_value()
+                }
+            }
+        )*
+    }
+}
+
+/// Implements fallible conversions out of a `YololNumber` into float types.
+macro_rules! impl_try_into_float {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl<T: YololOps, R: YololRange> TryFrom<YololNumber<T, R>> for $t
+            {
+                type Error = ConversionError;
+
+                fn try_from(input: YololNumber<T, R>) -> Result<Self, Self::Error>
+                {
+                    input.try_into_float_value()
+                }
+            }
+        )*
+    }
+}
+
+impl_from_small_int!(i8, i16, i32, u8, u16, u32);
+impl_try_from_large_int!(i64, i128, u64);
+impl_try_from_float!(f32, f64);
+
+impl_try_into_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+impl_try_into_float!(f32, f64);
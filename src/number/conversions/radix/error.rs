@@ -0,0 +1,39 @@
+use core::fmt;
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FromStrRadixError
+{
+    /// The radix isn't in the supported range of 2 to 36.
+    InvalidRadix(u32),
+
+    /// No main digits (left of the decimal) were in the input.
+    NoMainDigits,
+    /// A character that isn't a digit in the radix was found.
+    InvalidDigit(char),
+}
+
+impl fmt::Display for FromStrRadixError
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        match self
+        {
+            FromStrRadixError::InvalidRadix(radix) => write!(f, "Radix {} isn't supported, it must be between 2 and 36.", radix),
+            FromStrRadixError::NoMainDigits => f.write_str("No main digits (left of the decimal) were found in the input."),
+            FromStrRadixError::InvalidDigit(digit) => write!(f, "Found the character {:?}, which isn't a valid digit in the given radix.", digit),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromStrRadixError {}
+
+#[cfg(feature = "std")]
+impl From<FromStrRadixError> for String
+{
+    fn from(input: FromStrRadixError) -> Self
+    {
+        input.to_string()
+    }
+}
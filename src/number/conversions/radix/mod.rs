@@ -0,0 +1,197 @@
+use core::ops::RangeInclusive;
+
+use super::{YololNumber, RawInner};
+
+use crate::number::report::{self, OpKind, OpReport};
+
+use crate::traits::{YololOps, YololRange};
+
+pub mod error;
+use error::FromStrRadixError as Error;
+
+/// The radixes that are supported, which are the same ones `char::to_digit` supports.
+const RADIX_RANGE: RangeInclusive<u32> = 2..=36;
+
+impl<T: YololOps, R: YololRange> YololNumber<T, R>
+{
+    /// Formats the number in the given radix, such as `-1f.8` in base 16 for `-31.5`.
+    /// The decimals are the shortest digits which parse back to the exact same number.
+    ///
+    /// # Panics
+    /// Panics if the radix isn't between 2 and 36.
+    #[cfg(feature = "std")]
+    pub fn to_string_radix(self, radix: u32) -> String
+    {
+        assert!(RADIX_RANGE.contains(&radix), "[YololNumber::to_string_radix] Radix must be between 2 and 36, got {}!", radix);
+
+        let conversion = Self::conversion_val::<T>();
+        let radix_num = T::from(radix)
+            .expect("[YololNumber::to_string_radix] Inner type is unable to express the radix! Pick a better inner type...");
+
+        // Splitting before taking the absolute value means this can't overflow at the bottom of the range
+        let mut main = (self.0 / conversion).abs();
+        let decimal = (self.0 % conversion).abs().to_u128()
+            .expect("[YololNumber::to_string_radix] Decimal places are somehow out of range!");
+
+        let mut main_digits = Vec::new();
+        loop
+        {
+            let digit = (main % radix_num).to_u32()
+                .expect("[YololNumber::to_string_radix] Digit is somehow out of range!");
+
+            main_digits.push(Self::radix_digit(digit, radix));
+            main = main / radix_num;
+
+            if main.is_zero() { break }
+        }
+
+        let mut output = String::new();
+
+        if self.0 < T::zero()
+        {
+            output.push('-');
+        }
+
+        output.extend(main_digits.iter().rev());
+
+        if decimal != 0
+        {
+            output.push('.');
+            output.push_str(&Self::format_radix_decimal(decimal, radix));
+        }
+
+        output
+    }
+
+    /// Parses a number written in the given radix. Digits right of the decimal are converted into the
+    /// decimal places of a `YololNumber`, truncating anything past that just like parsing in base 10 does.
+    /// Values out of range saturate at the bounds of a `YololNumber`, also the same as parsing in base 10.
+    pub(crate) fn parse_radix(input: &str, radix: u32) -> Result<Self, Error>
+    {
+        if !RADIX_RANGE.contains(&radix)
+        {
+            return Err(Error::InvalidRadix(radix));
+        }
+
+        let (negative, digits) = match input.chars().next()
+        {
+            Some('-') => (true, &input[1..]),
+            Some('+') => (false, &input[1..]),
+            _ => (false, input)
+        };
+
+        let (main_digits, decimal_digits) = match digits.find('.')
+        {
+            Some(index) => (&digits[..index], &digits[index + 1..]),
+            None => (digits, "")
+        };
+
+        if main_digits.is_empty()
+        {
+            return Err(Error::NoMainDigits);
+        }
+
+        let radix_num = T::from(radix)
+            .expect("[YololNumber::parse_radix] Inner type is unable to express the radix! Pick a better inner type...");
+
+        // Negative numbers are built up by subtracting each digit, so the bottom
+        // of the range can be reached even when the backing type's range is the same.
+        let push_digit = |acc: T, digit: T| if negative { acc.checked_sub(&digit) } else { acc.checked_add(&digit) };
+
+        // Once the value goes out of range it only gets further out, so the rest of the digits are just checked
+        let main = main_digits.chars().try_fold(Some(T::zero()), |acc, c| {
+            let digit = c.to_digit(radix).ok_or(Error::InvalidDigit(c))?;
+
+            Ok(acc.and_then(|acc| acc.checked_mul(&radix_num))
+                .and_then(|acc| push_digit(acc, T::from(digit)?)))
+        })?;
+
+        let (decimal, truncated) = Self::parse_radix_decimal(decimal_digits, radix)?;
+
+        let inner = main.and_then(|main| main.checked_mul(&Self::conversion_val()))
+            .and_then(|main| push_digit(main, T::from(decimal)?));
+
+        // Anything out of range saturates, the same as parsing with `FromStr`
+        let (num, report) = match inner.map(|inner| Self::try_from_raw(RawInner(inner)))
+        {
+            Some(Ok(num)) => (num, OpReport::default()),
+            _ => Self::saturate(!negative),
+        };
+
+        Ok(report::observe(OpKind::Parse, (num, report | OpReport::truncation(truncated))).0)
+    }
+
+    /// Converts the digits right of the decimal into the decimal places of a `YololNumber`, truncating.
+    /// Also gives whether anything was truncated, which is any fraction of a place left over.
+    fn parse_radix_decimal(digits: &str, radix: u32) -> Result<(u128, bool), Error>
+    {
+        let conversion = 10_u128.pow(Self::num_places());
+        let radix_num = u128::from(radix);
+
+        // The decimal is tracked as the fraction `numerator / denominator`
+        let mut numerator = 0_u128;
+        let mut denominator = 1_u128;
+        let mut dropped_digits = false;
+
+        for c in digits.chars()
+        {
+            let digit = c.to_digit(radix).ok_or(Error::InvalidDigit(c))?;
+
+            // Once the denominator gets this big the digits are far too small to affect the
+            // output, so past here we only check they're valid and whether they're worth reporting.
+            if denominator.checked_mul(radix_num * conversion).is_some()
+            {
+                numerator = numerator * radix_num + u128::from(digit);
+                denominator *= radix_num;
+            }
+            else
+            {
+                dropped_digits |= digit != 0;
+            }
+        }
+
+        let truncated = dropped_digits || !(numerator * conversion).is_multiple_of(denominator);
+        Ok((numerator * conversion / denominator, truncated))
+    }
+
+    /// Finds the shortest digits in the radix which parse back to the given decimal places.
+    /// Parsing truncates, so any fraction in `[decimal, decimal + 1) / conversion` will work.
+    #[cfg(feature = "std")]
+    fn format_radix_decimal(decimal: u128, radix: u32) -> String
+    {
+        let conversion = 10_u128.pow(Self::num_places());
+        let radix_num = u128::from(radix);
+
+        let mut scale = 1_u128;
+        loop
+        {
+            scale *= radix_num;
+
+            // The smallest fraction with this many digits that's still at least the lower bound
+            let digits = (decimal * scale).div_ceil(conversion);
+
+            if digits * conversion < (decimal + 1) * scale
+            {
+                let mut output = Vec::new();
+                let mut remaining = digits;
+
+                // Going until the scale runs out keeps any leading zeros
+                while scale > 1
+                {
+                    output.push(Self::radix_digit((remaining % radix_num) as u32, radix));
+                    remaining /= radix_num;
+                    scale /= radix_num;
+                }
+
+                return output.iter().rev().collect();
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn radix_digit(digit: u32, radix: u32) -> char
+    {
+        core::char::from_digit(digit, radix)
+            .expect("[YololNumber::radix_digit] Digit is somehow too large for the radix!")
+    }
+}
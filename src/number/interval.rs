@@ -0,0 +1,362 @@
+use core::ops::{
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Neg,
+    Not,
+};
+
+use num_traits::{
+    AsPrimitive,
+    Bounded,
+    CheckedNeg,
+    One,
+    Signed,
+    Zero,
+};
+
+use super::YololNumber;
+use super::raw_inner::RawInner;
+
+use crate::float_math;
+use crate::traits::{YololOps, YololRange, StandardRange};
+
+/// Every `YololNumber` from `lo` to `hi` inclusive, for finding the range of results a formula can give.
+///
+/// Each operation gives an interval containing every result the operation can give on numbers in the inputs,
+/// with the same saturation and truncation as the regular operators, so if a result interval doesn't reach
+/// either bound then the formula can't have saturated. The integer operators give the tightest interval possible,
+/// while the float functions trust that the float math is monotonic between its turning points.
+#[derive(Debug, Clone, Copy)]
+pub struct YololInterval<T: YololOps, R: YololRange = StandardRange>
+{
+    lo: YololNumber<T, R>,
+    hi: YololNumber<T, R>,
+}
+
+impl<T: YololOps, R: YololRange> YololInterval<T, R>
+{
+    /// Creates the interval between the two numbers, which can be given in either order.
+    pub fn new(a: YololNumber<T, R>, b: YololNumber<T, R>) -> Self
+    {
+        YololInterval { lo: a.min(b), hi: a.max(b) }
+    }
+
+    /// Creates the interval containing just the one number.
+    pub fn point(num: YololNumber<T, R>) -> Self
+    {
+        YololInterval { lo: num, hi: num }
+    }
+
+    /// Creates the interval containing every expressible `YololNumber`.
+    pub fn full() -> Self
+    {
+        YololInterval { lo: YololNumber::min_value(), hi: YololNumber::max_value() }
+    }
+
+    /// Returns the smallest number in the interval.
+    pub fn lo(self) -> YololNumber<T, R>
+    {
+        self.lo
+    }
+
+    /// Returns the largest number in the interval.
+    pub fn hi(self) -> YololNumber<T, R>
+    {
+        self.hi
+    }
+
+    /// Returns whether the number is in the interval.
+    pub fn contains(self, num: YololNumber<T, R>) -> bool
+    {
+        self.lo <= num && num <= self.hi
+    }
+
+    /// Returns whether zero is in the interval, such as to check a divisor can never be zero.
+    pub fn contains_zero(self) -> bool
+    {
+        self.contains(YololNumber::zero())
+    }
+
+    /// Returns whether the interval reaches the minimum or maximum value. A result that
+    /// doesn't reach either bound can't have saturated in any step that produced it.
+    pub fn touches_bounds(self) -> bool
+    {
+        self.lo == YololNumber::min_value() || self.hi == YololNumber::max_value()
+    }
+
+    /// Returns the smallest interval containing both intervals.
+    pub fn hull(self, other: Self) -> Self
+    {
+        YololInterval { lo: self.lo.min(other.lo), hi: self.hi.max(other.hi) }
+    }
+
+    /// Returns the smallest interval containing all the numbers.
+    fn hull_of(nums: impl IntoIterator<Item = YololNumber<T, R>>) -> Self
+    {
+        let mut nums = nums.into_iter();
+        let first = nums.next()
+            .expect("[YololInterval::hull_of] Needs at least one number to make an interval!");
+
+        nums.fold(Self::point(first), |out, num| out.hull(Self::point(num)))
+    }
+
+    /// Applies the operation to every pair of endpoints. When an operation only ever moves one way as either input
+    /// changes, like the yolol operators do, the results at the corners are the most extreme results possible.
+    fn corners(self, other: Self, op: impl Fn(YololNumber<T, R>, YololNumber<T, R>) -> YololNumber<T, R>) -> Self
+    {
+        Self::hull_of([
+            op(self.lo, other.lo),
+            op(self.lo, other.hi),
+            op(self.hi, other.lo),
+            op(self.hi, other.hi),
+        ])
+    }
+
+    /// The smallest number above zero.
+    fn epsilon() -> YololNumber<T, R>
+    {
+        YololNumber::from_raw(RawInner(T::one()))
+    }
+}
+
+impl<T: YololOps, R: YololRange> PartialEq for YololInterval<T, R>
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.lo == other.lo && self.hi == other.hi
+    }
+}
+
+impl<T: YololOps, R: YololRange> Eq for YololInterval<T, R> {}
+
+impl<T: YololOps, R: YololRange> Add for YololInterval<T, R>
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self
+    {
+        YololInterval { lo: self.lo + other.lo, hi: self.hi + other.hi }
+    }
+}
+impl_for_refs!( impl<T: YololOps, R: YololRange> Add for YololInterval<T, R> { fn add() -> Self } );
+
+impl<T: YololOps, R: YololRange> Sub for YololInterval<T, R>
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self
+    {
+        YololInterval { lo: self.lo - other.hi, hi: self.hi - other.lo }
+    }
+}
+impl_for_refs!( impl<T: YololOps, R: YololRange> Sub for YololInterval<T, R> { fn sub() -> Self } );
+
+impl<T: YololOps, R: YololRange> Mul for YololInterval<T, R>
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self
+    {
+        self.corners(other, |a, b| a * b)
+    }
+}
+impl_for_refs!( impl<T: YololOps, R: YololRange> Mul for YololInterval<T, R> { fn mul() -> Self } );
+
+impl<T: YololOps, R: YololRange> Div for YololInterval<T, R>
+{
+    type Output = Self;
+
+    /// Includes `0` if the divisor can be zero, as that's what yolol division gives.
+    fn div(self, other: Self) -> Self
+    {
+        let zero = YololNumber::zero();
+        let epsilon = Self::epsilon();
+
+        // Division only moves one way on either side of zero, so each side is handled
+        // separately, with the divisors closest to zero being the smallest ones that exist
+        let negative = (other.lo < zero)
+            .then(|| self.corners(YololInterval::new(other.lo, other.hi.min(-epsilon)), |a, b| a / b));
+
+        let positive = (other.hi > zero)
+            .then(|| self.corners(YololInterval::new(other.lo.max(epsilon), other.hi), |a, b| a / b));
+
+        let by_zero = other.contains_zero()
+            .then(|| Self::point(zero));
+
+        IntoIterator::into_iter([negative, positive, by_zero])
+            .flatten()
+            .reduce(Self::hull)
+            .expect("[YololInterval::div] An interval always has at least one number in it!")
+    }
+}
+impl_for_refs!( impl<T: YololOps, R: YololRange> Div for YololInterval<T, R> { fn div() -> Self } );
+
+impl<T: YololOps, R: YololRange> Rem for YololInterval<T, R>
+{
+    type Output = Self;
+
+    /// Includes `0` if the divisor can be zero, as that's what yolol modulo gives.
+    fn rem(self, other: Self) -> Self
+    {
+        let zero = YololNumber::zero();
+
+        // When the dividend is always closer to zero than the divisor, nothing is ever taken off of it
+        let smallest_divisor = other.lo.abs().min(other.hi.abs());
+        let largest_dividend = self.lo.abs().max(self.hi.abs());
+
+        if !other.contains_zero() && largest_dividend < smallest_divisor
+        {
+            return self;
+        }
+
+        // Otherwise the remainder takes the sign of the dividend, is never further from zero than the
+        // dividend, and is always closer to zero than the divisor. The range can be lopsided, so a
+        // divisor that can't be negated is further from zero than any remainder on that side
+        let epsilon = Self::epsilon();
+
+        let lowest = other.hi.checked_neg()
+            .map_or_else(YololNumber::min_value, |neg| (other.lo.min(neg) + epsilon).min(zero));
+
+        let highest = other.lo.checked_neg()
+            .map_or_else(YololNumber::max_value, |neg| (other.hi.max(neg) - epsilon).max(zero));
+
+        YololInterval {
+            lo: if self.lo < zero { self.lo.max(lowest) } else { zero },
+            hi: if self.hi > zero { self.hi.min(highest) } else { zero },
+        }
+    }
+}
+impl_for_refs!( impl<T: YololOps, R: YololRange> Rem for YololInterval<T, R> { fn rem() -> Self } );
+
+impl<T: YololOps, R: YololRange> Neg for YololInterval<T, R>
+{
+    type Output = Self;
+    fn neg(self) -> Self
+    {
+        YololInterval { lo: -self.hi, hi: -self.lo }
+    }
+}
+
+impl<T: YololOps, R: YololRange> Not for YololInterval<T, R>
+{
+    type Output = Self;
+    fn not(self) -> Self
+    {
+        if self == Self::point(YololNumber::falsy()) {
+            Self::point(YololNumber::truthy())
+        } else if self.contains_zero() {
+            YololInterval { lo: YololNumber::falsy(), hi: YololNumber::truthy() }
+        } else {
+            Self::point(YololNumber::falsy())
+        }
+    }
+}
+
+// Past this many degrees the conversion to radians is too imprecise to find the turning points of the trig functions
+const MAX_PRECISE_DEGREES: f64 = 1_000_000.0;
+
+impl<T: YololOps + AsPrimitive<f64>, R: YololRange> YololInterval<T, R>
+where f64: AsPrimitive<T>
+{
+    /// Saturates at the maximum value when raising zero to a negative power, like the float math does.
+    /// If the base can be negative, the result isn't predictable enough to do better than every number.
+    pub fn pow(self, other: Self) -> Self
+    {
+        if self.lo.is_negative()
+        {
+            return Self::full();
+        }
+
+        // For a base that isn't negative, the power only moves one way as either input changes
+        self.corners(other, YololNumber::pow)
+    }
+
+    /// Includes the minimum value if the input can be negative, as that's what the NaN from the float math becomes.
+    pub fn sqrt(self) -> Self
+    {
+        self.within_domain(YololNumber::sqrt, YololNumber::zero(), YololNumber::max_value())
+    }
+
+    /// Takes the sine of the interval in degrees.
+    pub fn sin(self) -> Self
+    {
+        self.periodic(YololNumber::sin, 90.0)
+    }
+
+    /// Takes the cosine of the interval in degrees.
+    pub fn cos(self) -> Self
+    {
+        self.periodic(YololNumber::cos, 0.0)
+    }
+
+    /// Takes the tangent of the interval in degrees.
+    /// Every number is included if the interval gets to an asymptote, where the tangent can be anything.
+    pub fn tan(self) -> Self
+    {
+        let (lo, hi) = (self.lo.float_value(), self.hi.float_value());
+        let next_asymptote = float_math::ceil((lo - 90.0) / 180.0) * 180.0 + 90.0;
+
+        if next_asymptote <= hi || lo.abs().max(hi.abs()) > MAX_PRECISE_DEGREES
+        {
+            return Self::full();
+        }
+
+        YololInterval { lo: self.lo.tan(), hi: self.hi.tan() }
+    }
+
+    /// Takes the arcsine of the interval in degrees. Includes the minimum value if
+    /// the input can be outside of `-1` to `1`, as that's what the NaN from the float math becomes.
+    pub fn asin(self) -> Self
+    {
+        self.within_domain(YololNumber::asin, -YololNumber::one(), YololNumber::one())
+    }
+
+    /// Takes the arccosine of the interval in degrees. Includes the minimum value if
+    /// the input can be outside of `-1` to `1`, as that's what the NaN from the float math becomes.
+    pub fn acos(self) -> Self
+    {
+        self.within_domain(YololNumber::acos, -YololNumber::one(), YololNumber::one())
+    }
+
+    /// Takes the arctangent of the interval in degrees.
+    pub fn atan(self) -> Self
+    {
+        YololInterval { lo: self.lo.atan(), hi: self.hi.atan() }
+    }
+
+    /// Applies a function that only moves one way within its domain, and gives NaN outside of it.
+    fn within_domain(self, op: fn(YololNumber<T, R>) -> YololNumber<T, R>, lo: YololNumber<T, R>, hi: YololNumber<T, R>) -> Self
+    {
+        let outside = (self.lo < lo || self.hi > hi)
+            .then(|| Self::point(YololNumber::min_value()));
+
+        let inside = (self.lo <= hi && self.hi >= lo)
+            .then(|| Self::hull_of([op(self.lo.max(lo)), op(self.hi.min(hi))]));
+
+        IntoIterator::into_iter([inside, outside])
+            .flatten()
+            .reduce(Self::hull)
+            .expect("[YololInterval::within_domain] An interval is always either inside or outside of the domain!")
+    }
+
+    /// Applies sine or cosine, which turn around every 180 degrees starting from `first_turn`.
+    /// Between turning points they only move one way, so only the endpoints and turning points matter.
+    fn periodic(self, op: fn(YololNumber<T, R>) -> YololNumber<T, R>, first_turn: f64) -> Self
+    {
+        let (lo, hi) = (self.lo.float_value(), self.hi.float_value());
+
+        // Covering a full turn means every result is possible
+        if hi - lo >= 360.0 || lo.abs().max(hi.abs()) > MAX_PRECISE_DEGREES
+        {
+            return Self::hull_of(IntoIterator::into_iter([first_turn, first_turn + 180.0])
+                .map(|turn| op(YololNumber::from_float(turn))));
+        }
+
+        let next_turn = float_math::ceil((lo - first_turn) / 180.0) * 180.0 + first_turn;
+        let turns = IntoIterator::into_iter([next_turn, next_turn + 180.0])
+            .filter(|&turn| turn <= hi)
+            .map(YololNumber::from_float);
+
+        Self::hull_of(IntoIterator::into_iter([self.lo, self.hi]).chain(turns).map(op))
+    }
+}
@@ -0,0 +1,166 @@
+use core::cmp;
+
+use core::ops::{
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+};
+
+use super::YololNumber;
+
+use crate::traits::{
+    YololOps,
+    YololRange,
+    ArgBounds,
+};
+
+// Mixing primitives into operations with a `YololNumber` always uses value semantics, so
+// `num + 5` adds the value five. The primitive gets converted into a `YololNumber` first with `from_value`,
+// meaning a float has any decimals past the places truncated, and the operation itself saturates the exact
+// same way as between two `YololNumber`s.
+// Comparisons are different, they're always against the exact value of the primitive,
+// so nothing is equal to a primitive that a `YololNumber` can't express exactly.
+
+impl<T: YololOps, R: YololRange> YololNumber<T, R>
+{
+    /// Compares against the exact value of an integer, which is shifted up into a raw inner in an `i128`
+    /// instead of being converted, since converting saturates. An `i128` can hold any shifted `i64`.
+    fn compare_int(self, input: impl ArgBounds<T>) -> Option<cmp::Ordering>
+    {
+        let inner = self.0.to_i128()?;
+        let other = input.to_i128()?.checked_mul(Self::conversion_val::<T>().to_i128()?)?;
+
+        Some(inner.cmp(&other))
+    }
+
+    /// Compares against the exact value of a float, instead of rounding it to the nearest `YololNumber`.
+    /// Nothing is equal to NaN, so there's no ordering for it.
+    fn compare_float(self, input: f64) -> Option<cmp::Ordering>
+    {
+        if input.is_nan()
+        {
+            return None;
+        }
+
+        if input.is_infinite()
+        {
+            return Some(if input > 0.0 { cmp::Ordering::Less } else { cmp::Ordering::Greater });
+        }
+
+        let inner = self.0.to_i128()?;
+        let conversion = Self::conversion_val::<T>().to_i128()?;
+
+        // Every finite float is exactly `mantissa * 2^exponent`, so the raw inner it's equal to
+        // is `mantissa * conversion * 2^exponent`, which can be compared without any rounding
+        let bits = input.to_bits();
+        let exponent_bits = ((bits >> 52) & 0x7FF) as i32;
+        let fraction = i128::from(bits & ((1 << 52) - 1));
+
+        let (mantissa, exponent) = if exponent_bits == 0 { (fraction, -1074) } else { (fraction | (1 << 52), exponent_bits - 1075) };
+
+        // The mantissa is under 2^53 and the conversion value is small, so this stays under 2^64
+        let scaled = mantissa * conversion;
+        let negative = input.is_sign_negative();
+
+        if exponent >= 0
+        {
+            // Past this the float is further from zero than any `i128`
+            if exponent > 64
+            {
+                return Some(if negative { cmp::Ordering::Greater } else { cmp::Ordering::Less });
+            }
+
+            let other = scaled << exponent;
+            return Some(inner.cmp(&if negative { -other } else { other }));
+        }
+
+        // Splits the shifted float into its whole part and whether there's any fraction left below it
+        let shift = (-exponent) as u32;
+        let (whole, has_fraction) = if shift >= 127 { (0, scaled != 0) } else { (scaled >> shift, scaled & ((1 << shift) - 1) != 0) };
+
+        // Any fraction left over puts the float just past the whole part, away from zero,
+        // so it only changes the ordering when the inner is equal to the whole part
+        let ordering = if negative { inner.cmp(&-whole) } else { inner.cmp(&whole) };
+
+        Some(match ordering
+        {
+            cmp::Ordering::Equal if has_fraction && negative => cmp::Ordering::Greater,
+            cmp::Ordering::Equal if has_fraction => cmp::Ordering::Less,
+            ordering => ordering,
+        })
+    }
+}
+
+/// Implements an operator between a `YololNumber` and a primitive, in both directions.
+macro_rules! impl_mixed_op {
+    ( $imp:ident, $method:ident, $prim:ty, $convert:ident, { $( $w:tt )* } ) => {
+        impl<T: YololOps, R: YololRange> $imp<$prim> for YololNumber<T, R> where $( $w )*
+        {
+            type Output = Self;
+            fn $method(self, other: $prim) -> Self
+            {
+                $imp::$method(self, YololNumber::$convert(other))
+            }
+        }
+        impl_for_refs!( impl<T: YololOps, R: YololRange> $imp for YololNumber<T, R> where { $( $w )* } { fn $method() -> $prim } );
+
+        impl<T: YololOps, R: YololRange> $imp<YololNumber<T, R>> for $prim where $( $w )*
+        {
+            type Output = YololNumber<T, R>;
+            fn $method(self, other: YololNumber<T, R>) -> YololNumber<T, R>
+            {
+                $imp::$method(YololNumber::$convert(self), other)
+            }
+        }
+        impl_for_refs!( impl<T: YololOps, R: YololRange> $imp for $prim where { $( $w )* } { fn $method() -> YololNumber<T, R> } );
+    }
+}
+
+/// Implements all the operators and comparisons between a `YololNumber` and a primitive.
+macro_rules! impl_mixed {
+    ( $prim:ty => $convert:ident, $compare:ident where { $( $w:tt )* } ) => {
+        impl_mixed_op!(Add, add, $prim, $convert, { $( $w )* });
+        impl_mixed_op!(Sub, sub, $prim, $convert, { $( $w )* });
+        impl_mixed_op!(Mul, mul, $prim, $convert, { $( $w )* });
+        impl_mixed_op!(Div, div, $prim, $convert, { $( $w )* });
+        impl_mixed_op!(Rem, rem, $prim, $convert, { $( $w )* });
+
+        impl<T: YololOps, R: YololRange> cmp::PartialEq<$prim> for YololNumber<T, R> where $( $w )*
+        {
+            fn eq(&self, other: &$prim) -> bool
+            {
+                self.$compare(*other) == Some(cmp::Ordering::Equal)
+            }
+        }
+
+        impl<T: YololOps, R: YololRange> cmp::PartialEq<YololNumber<T, R>> for $prim where $( $w )*
+        {
+            fn eq(&self, other: &YololNumber<T, R>) -> bool
+            {
+                other == self
+            }
+        }
+
+        impl<T: YololOps, R: YololRange> cmp::PartialOrd<$prim> for YololNumber<T, R> where $( $w )*
+        {
+            fn partial_cmp(&self, other: &$prim) -> Option<cmp::Ordering>
+            {
+                self.$compare(*other)
+            }
+        }
+
+        impl<T: YololOps, R: YololRange> cmp::PartialOrd<YololNumber<T, R>> for $prim where $( $w )*
+        {
+            fn partial_cmp(&self, other: &YololNumber<T, R>) -> Option<cmp::Ordering>
+            {
+                other.partial_cmp(self).map(cmp::Ordering::reverse)
+            }
+        }
+    }
+}
+
+impl_mixed!(i32 => from_value, compare_int where { i32: ArgBounds<T> });
+impl_mixed!(i64 => from_value, compare_int where { i64: ArgBounds<T> });
+impl_mixed!(f64 => from_value, compare_float where { f64: ArgBounds<T> });
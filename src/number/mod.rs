@@ -1,6 +1,8 @@
 use num_traits::{
     self,
     Bounded,
+    CheckedAdd,
+    CheckedMul,
     One,
     Zero,
     cast::{
@@ -9,45 +11,221 @@ use num_traits::{
     },
 };
 
+use core::marker::PhantomData;
+
 use crate::traits::{
     YololOps,
+    YololRange,
+    StandardRange,
     ArgBounds,
 };
+use crate::float_math;
+use crate::traits::range::range_is_valid;
+
+use conversions::error::ConversionError;
+use conversions::radix::error::FromStrRadixError;
+use raw_inner::RawInner;
+use report::{OpKind, OpReport};
 
 mod ops;
+mod mixed_ops;
 pub mod conversions;
 mod serde_impl;
+mod num_traits_impl;
+mod consts;
+mod slice;
+pub mod raw_inner;
+pub mod semantics;
+pub mod report;
+pub mod shadowed;
+pub mod interval;
+#[cfg(target_has_atomic = "64")]
+pub mod atomic;
 
 /// The single canonical definition of how many decimals places exist in a `YololNumber`.
 /// At least that's the goal, _most_ of the code uses this, but not all.
 const NUMBER_OF_PLACES: u8 = 3;
 
+/// A number with the semantics of a number in yolol, stored as a raw inner of type `T`.
+/// The range of values it can express is set by `R`, which defaults to the range in the game.
+///
+/// It has the exact same layout as `T`, so slices of raw inners can be viewed as slices of numbers, see `try_from_raw_slice`.
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone, Copy)]
-pub struct YololNumber<T: YololOps>(T);
+#[repr(transparent)]
+pub struct YololNumber<T: YololOps, R: YololRange = StandardRange>(T, PhantomData<R>);
 
-impl<T: YololOps> YololNumber<T>
+impl<T: YololOps, R: YololRange> YololNumber<T, R>
 {
     /// Creates a `YololNumber` with the same value as the input. This will shift the input as necessary.
-    /// Does an unchecked `as` cast, so the value may be lossy if misused.
+    /// Saturates at the bounds of a `YololNumber`, see `try_from_value` for a checked version.
     pub fn from_value(input: impl ArgBounds<T>) -> Self
     {
-        let inner = Self::make_inner(input.as_());
-        YololNumber(inner).bound()
+        Self::saturating_from_value(input)
     }
 
-    /// Creates a `YololNumber` with the input directly used as the raw inner. 
-    /// Does an unchecked `as` cast, so the value may be lossy if misused.
+    /// Creates a `YololNumber` with the input directly used as the raw inner.
+    /// Saturates at the bounds of a `YololNumber`.
+    #[deprecated(note = "Mixes up raw inners and values too easily, use `from_raw` with a `RawInner` instead")]
     pub fn from_inner(input: impl ArgBounds<T>) -> Self
     {
-        YololNumber(input.as_()).bound()
+        Self::from_raw(RawInner(input))
+    }
+
+    /// Creates a `YololNumber` with the same value as the input, shifting the input as necessary.
+    /// Fails if the input can't be expressed exactly, such as a float with decimals past the places of a `YololNumber`.
+    pub fn try_from_value(input: impl ArgBounds<T>) -> Result<Self, ConversionError>
+    {
+        match Self::shift_value(input)?
+        {
+            (inner, false) => Self::try_from_raw(RawInner(inner)),
+            (_, true) => Err(ConversionError::Truncated),
+        }
+    }
+
+    /// Creates a `YololNumber` with the same value as the input, shifting the input as necessary.
+    /// Values out of range are clamped to the bounds of a `YololNumber`, any decimal digits
+    /// past the places of a `YololNumber` are truncated, and NaN becomes the minimum value.
+    pub fn saturating_from_value(input: impl ArgBounds<T>) -> Self
+    {
+        Self::from_value_reporting(input).0
+    }
+
+    /// Creates a `YololNumber` with the same value as the input, the same as `saturating_from_value`,
+    /// reporting if the result saturated, had decimals truncated, or was mapped from NaN.
+    pub fn from_value_reporting(input: impl ArgBounds<T>) -> (Self, OpReport)
+    {
+        report::observe(OpKind::Conversion, Self::value_with_report(input))
+    }
+
+    fn value_with_report<F: ArgBounds<T>>(input: F) -> (Self, OpReport)
+    {
+        match Self::shift_value(input)
+        {
+            Ok((inner, truncated)) => {
+                let (num, report) = Self::raw_with_report(RawInner(inner));
+                (num, report | OpReport::truncation(truncated))
+            },
+
+            Err(error) => Self::conversion_fallback(error, || {
+                // Now that we know it fits, we can safely do the lossy cast into the backing type
+                Self::value_with_report(input.as_())
+            })
+        }
+    }
+
+    /// Shifts the input up into a raw inner, along with whether any decimals past the places of a `YololNumber`
+    /// had to be truncated. Fails if the shifted input doesn't fit in the backing type.
+    fn shift_value<F: ArgBounds<T>>(input: F) -> Result<(T, bool), ConversionError>
+    {
+        match Self::checked_backing(input)
+        {
+            Ok(value) => value.checked_mul(&Self::conversion_val())
+                .map(|inner| (inner, false))
+                .ok_or_else(|| ConversionError::out_of_range(value.is_negative())),
+
+            // Only decimals past the places count as truncated, so they're shifted up before checking again
+            Err(ConversionError::Truncated) => {
+                let conversion: f64 = NumCast::from(Self::conversion_val::<T>())
+                    .expect("[YololNumber::shift_value] Conversion value can't be expressed in an f64!");
+
+                let shifted = input.to_f64().ok_or(ConversionError::Truncated)? * conversion;
+
+                // Most decimals aren't exact in binary, so `1.005` shifts to `1004.999...`. Anything within
+                // the rounding error of the float is the decimal it was written as, and only the rest truncates.
+                // A widened `f32` still only has the precision of an `f32`, so it gets a wider margin
+                let epsilon = if core::mem::size_of::<F>() == 4 { <f64 as From<f32>>::from(f32::EPSILON) } else { f64::EPSILON };
+                let rounded = float_math::round(shifted);
+                let is_exact = (shifted - rounded).abs() <= shifted.abs() * epsilon * 2.0;
+                let kept = if is_exact { rounded } else { shifted };
+
+                // Converting from a float truncates towards zero, so anything left over didn't fit in the places
+                let inner = T::from(kept)
+                    .ok_or_else(|| ConversionError::out_of_range(shifted.is_sign_negative()))?;
+
+                Ok((inner, !is_exact))
+            },
+
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Creates a `YololNumber` from a raw inner value.
+    /// Fails if the input can't be expressed exactly.
+    pub fn try_from_raw<F: ArgBounds<T>>(input: RawInner<F>) -> Result<Self, ConversionError>
+    {
+        let inner = Self::checked_backing(input.0)?;
+
+        if inner > Self::max_value().0
+        {
+            Err(ConversionError::Overflow)
+        }
+        else if inner < Self::min_value().0
+        {
+            Err(ConversionError::Underflow)
+        }
+        else
+        {
+            Ok(YololNumber(inner, PhantomData))
+        }
+    }
+
+    /// Creates a `YololNumber` from a raw inner value.
+    /// Values out of range are clamped to the bounds of a `YololNumber`, any
+    /// extra decimal digits are truncated, and NaN becomes the minimum value.
+    pub fn from_raw<F: ArgBounds<T>>(input: RawInner<F>) -> Self
+    {
+        Self::from_raw_reporting(input).0
+    }
+
+    /// Creates a `YololNumber` from a raw inner value, the same as `from_raw`,
+    /// reporting if the result saturated, had decimals truncated, or was mapped from NaN.
+    pub fn from_raw_reporting<F: ArgBounds<T>>(input: RawInner<F>) -> (Self, OpReport)
+    {
+        report::observe(OpKind::Conversion, Self::raw_with_report(input))
+    }
+
+    fn raw_with_report<F: ArgBounds<T>>(input: RawInner<F>) -> (Self, OpReport)
+    {
+        match Self::try_from_raw(input)
+        {
+            Ok(num) => (num, OpReport::default()),
+
+            Err(error) => Self::conversion_fallback(error, || {
+                // Now that we know it fits, we can safely do the lossy cast into the backing type
+                Self::raw_with_report(RawInner(input.0.as_()))
+            })
+        }
+    }
+
+    /// Picks the saturated result for a failed conversion, or retries with the truncated input.
+    fn conversion_fallback(error: ConversionError, truncated: impl FnOnce() -> (Self, OpReport)) -> (Self, OpReport)
+    {
+        match error
+        {
+            ConversionError::Overflow => Self::saturate(true),
+            ConversionError::Underflow => Self::saturate(false),
+            ConversionError::NotANumber => (Self::min_value(), OpReport { nan_mapped: true, ..OpReport::default() }),
+
+            ConversionError::Truncated => {
+                let (num, report) = truncated();
+                (num, report | OpReport::truncation(true))
+            }
+        }
     }
 
     /// Creates a `YololNumber` from values split into the main digits and decimal digits.
-    /// Checks the conversion, so the value is entirely lossless.
+    /// Checks the conversion into the backing type, and saturates if the value is out of range.
     pub fn from_split(main: impl ArgBounds<T>, decimal: impl ArgBounds<T>) -> Option<Self>
     {
-        let main = Self::make_inner(T::from(main)?);
+        Self::split_with_report(main, decimal)
+            .map(|output| report::observe(OpKind::Conversion, output).0)
+    }
+
+    /// Does the work of `from_split`, reporting if the result saturated.
+    pub(crate) fn split_with_report(main: impl ArgBounds<T>, decimal: impl ArgBounds<T>) -> Option<(Self, OpReport)>
+    {
+        let main = T::from(main)?;
 
         // Clamps the decimal to between -999 and 999, to ensure we don't get weirdness
         let decimal = {
@@ -55,15 +233,38 @@ impl<T: YololOps> YololNumber<T>
             val % Self::conversion_val()
         };
 
-        Some(YololNumber(main + decimal).bound())
+        // Shifting the main digits can overflow the backing type, so it's done in the wide type
+        let inner = main.widen().checked_mul(&Self::wide_conversion_val())
+            .and_then(|main| main.checked_add(&decimal.widen()))
+            .and_then(T::narrow);
+
+        match inner
+        {
+            Some(inner) => Some(Self::raw_with_report(RawInner(inner))),
+
+            None => Some(Self::saturate(!main.is_negative()))
+        }
     }
 
     /// Returns the raw inner value.
+    #[deprecated(note = "Mixes up raw inners and values too easily, use `to_raw` instead")]
     pub fn get_inner(self) -> T
     {
         self.0
     }
 
+    /// Returns the raw inner value.
+    pub fn to_raw(self) -> RawInner<T>
+    {
+        RawInner(self.0)
+    }
+
+    /// Creates a `YololNumber` from a raw inner that's already known to be in range, skipping the checks.
+    pub(crate) fn from_inner_unchecked(inner: T) -> Self
+    {
+        YololNumber(inner, PhantomData)
+    }
+
     /// Returns the truthy identity.
     pub fn truthy() -> Self
     {
@@ -88,7 +289,7 @@ impl<T: YololOps> YololNumber<T>
     pub fn get_value<F>(self) -> F
     where
         T: AsPrimitive<F>,
-        F: 'static + Copy + std::ops::Div<Output=F>
+        F: 'static + Copy + core::ops::Div<Output=F>
     {
         let inner: F = self.0.as_();
         inner / Self::conversion_val::<F>()
@@ -113,29 +314,33 @@ impl<T: YololOps> YololNumber<T>
             .expect("Using YololNumber with a backing type that can't express the conversion factor (10 ^ num_places)!").as_()
     }
 
-    /// Converts a given value to the raw inner that expresses it.
-    fn make_inner(num: T) -> T
+    /// Returns the conversion value in the wide type of the backing type.
+    fn wide_conversion_val() -> T::Wide
     {
-        num * Self::conversion_val()
+        NumCast::from(Self::conversion_val::<T>())
+            .expect("[YololNumber::wide_conversion_val] Wide type is unable to express the conversion value!")
     }
 
-    /// Treats the inputs as if it were a raw inner value.
-    /// This means it should be larger by a factor of the conversion value than the value you want.
-    fn try_to_inner<F: NumCast>(input: F) -> Option<Self>
+    /// Converts the input into the backing type, failing if it doesn't fit or would lose information.
+    fn checked_backing<F: ArgBounds<T>>(input: F) -> Result<T, ConversionError>
     {
-        // Converts it to T then maps the Some value to YololNumber<T>
-        T::from(input)
-            .map(YololNumber)
-    }
+        match T::from(input)
+        {
+            // Round tripping it back catches any decimals that got dropped
+            Some(num) if <F as NumCast>::from(num) == Some(input) => Ok(num),
+            Some(_) => Err(ConversionError::Truncated),
 
-    /// Directly outputs the raw inner value, does not scale it.
-    fn try_from_inner<F: NumCast>(&self) -> Option<F>
-    {
-        F::from(self.0)
+            None => match input.to_f64()
+            {
+                Some(float) if float.is_nan() => Err(ConversionError::NotANumber),
+                Some(float) => Err(ConversionError::out_of_range(float.is_sign_negative())),
+                None => Err(ConversionError::Overflow)
+            }
+        }
     }
 }
 
-impl<T: YololOps> num_traits::Zero for YololNumber<T>
+impl<T: YololOps, R: YololRange> num_traits::Zero for YololNumber<T, R>
 {
     /// Returns the value zero.
     fn zero() -> Self
@@ -150,7 +355,7 @@ impl<T: YololOps> num_traits::Zero for YololNumber<T>
     }
 }
 
-impl<T: YololOps> num_traits::One for YololNumber<T>
+impl<T: YololOps, R: YololRange> num_traits::One for YololNumber<T, R>
 {
     /// Returns the value one.
     fn one() -> Self
@@ -159,39 +364,68 @@ impl<T: YololOps> num_traits::One for YololNumber<T>
     }
 }
 
-impl<T: YololOps> num_traits::Num for YololNumber<T>
+impl<T: YololOps, R: YololRange> Default for YololNumber<T, R>
 {
-    type FromStrRadixErr = String;
+    /// Returns the value zero, the same as an unset yolol variable.
+    fn default() -> Self
+    {
+        YololNumber::zero()
+    }
+}
 
-    fn from_str_radix(input: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr>
+impl<T: YololOps + core::hash::Hash, R: YololRange> core::hash::Hash for YololNumber<T, R>
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H)
     {
-        if radix != 10
-        {
-            return Err("Only able to convert from strings in base 10!".to_owned());
-        }
+        self.0.hash(state);
+    }
+}
+
+impl<T: YololOps, R: YololRange> num_traits::Num for YololNumber<T, R>
+{
+    type FromStrRadixErr = FromStrRadixError;
 
-        input.parse::<YololNumber<T>>()
-            .map_err(|e| e.into())
+    /// Parses a number in any radix from 2 to 36, see `to_string_radix` for the opposite.
+    /// Values out of range saturate the same as `FromStr`.
+    fn from_str_radix(input: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr>
+    {
+        Self::parse_radix(input, radix)
     }
 }
 
-impl<T: YololOps> num_traits::Bounded for YololNumber<T>
+impl<T: YololOps, R: YololRange> YololNumber<T, R>
 {
-    /// Returns the minimum value expressible in a `YololNumber`
+    /// Stops compilation if the range breaks the invariants the math relies on, see `YololRange`.
+    /// Every operation goes through the bounds, so checking there catches any use of the range.
+    const RANGE_VALID: () = assert!(
+        range_is_valid(R::MIN_INNER, R::MAX_INNER, core::mem::size_of::<T>(), core::mem::size_of::<T::Wide>()),
+        "The range of a YololNumber has to contain zero, and the product of any two values in it has to fit in the wide type!"
+    );
+}
+
+impl<T: YololOps, R: YololRange> num_traits::Bounded for YololNumber<T, R>
+{
+    /// Returns the minimum value expressible in a `YololNumber`, as set by its range.
     fn min_value() -> Self
     {
-        let min = T::from(<i64 as num_traits::Bounded>::min_value())
+        #[allow(clippy::let_unit_value)]
+        let () = Self::RANGE_VALID;
+
+        let min = T::from(R::MIN_INNER)
             .unwrap_or_else(T::min_value);
 
-        YololNumber(min)
+        YololNumber(min, PhantomData)
     }
 
-    /// Returns the maximum value expressible in a `YololNumber`
+    /// Returns the maximum value expressible in a `YololNumber`, as set by its range.
     fn max_value() -> Self
     {
-        let max = T::from(<i64 as num_traits::Bounded>::max_value())
+        #[allow(clippy::let_unit_value)]
+        let () = Self::RANGE_VALID;
+
+        let max = T::from(R::MAX_INNER)
             .unwrap_or_else(T::max_value);
 
-        YololNumber(max)
+        YololNumber(max, PhantomData)
     }
 }
\ No newline at end of file
@@ -0,0 +1,323 @@
+use core::marker::PhantomData;
+
+use num_traits::{
+    AsPrimitive,
+    Bounded,
+    CheckedAdd,
+    CheckedDiv,
+    CheckedMul,
+    One,
+    Signed,
+    Zero,
+};
+
+use num_traits::ops::{
+    checked::CheckedNeg,
+    euclid::{Euclid, CheckedEuclid},
+    inv::Inv,
+    mul_add::MulAdd,
+    saturating::{Saturating, SaturatingAdd, SaturatingSub, SaturatingMul},
+    wrapping::{WrappingAdd, WrappingSub, WrappingMul},
+};
+
+use num_traits::pow::Pow;
+
+use super::YololNumber;
+use super::raw_inner::RawInner;
+
+use crate::traits::{YololOps, YololRange};
+
+// The yolol operators already saturate, so the saturating traits are just the regular operators.
+
+impl<T: YololOps, R: YololRange> Saturating for YololNumber<T, R>
+{
+    fn saturating_add(self, other: Self) -> Self
+    {
+        self + other
+    }
+
+    fn saturating_sub(self, other: Self) -> Self
+    {
+        self - other
+    }
+}
+
+impl<T: YololOps, R: YololRange> SaturatingAdd for YololNumber<T, R>
+{
+    fn saturating_add(&self, other: &Self) -> Self
+    {
+        self + other
+    }
+}
+
+impl<T: YololOps, R: YololRange> SaturatingSub for YololNumber<T, R>
+{
+    fn saturating_sub(&self, other: &Self) -> Self
+    {
+        self - other
+    }
+}
+
+impl<T: YololOps, R: YololRange> SaturatingMul for YololNumber<T, R>
+{
+    fn saturating_mul(&self, other: &Self) -> Self
+    {
+        self * other
+    }
+}
+
+impl<T: YololOps, R: YololRange> YololNumber<T, R>
+{
+    /// Wraps a raw inner around the bounds of a `YololNumber`, like a two's complement integer would.
+    /// The math is done in an i128, which the range check guarantees can hold the product of any two inners.
+    fn wrapping_new(inner: Option<i128>) -> Self
+    {
+        let min = Self::min_value().wrapping_inner();
+        let max = Self::max_value().wrapping_inner();
+
+        let wrapped = inner
+            .and_then(|inner| inner.checked_sub(min))
+            .zip(max.checked_sub(min).and_then(|span| span.checked_add(1)))
+            .and_then(|(offset, span)| offset.rem_euclid(span).checked_add(min))
+            .and_then(T::from)
+            .expect("[YololNumber::wrapping_new] Wrapping overflowed an i128! The range check is somehow broken :(");
+
+        YololNumber(wrapped, PhantomData)
+    }
+
+    /// Gets the raw inner as an i128 to do wrapping math on.
+    fn wrapping_inner(self) -> i128
+    {
+        self.0.to_i128()
+            .expect("[YololNumber::wrapping_inner] Inner can't be expressed in an i128!")
+    }
+
+    /// Gets the conversion value as an i128 to do wrapping math with.
+    fn wrapping_conversion() -> i128
+    {
+        Self::conversion_val::<T>().to_i128()
+            .expect("[YololNumber::wrapping_conversion] Conversion value can't be expressed in an i128!")
+    }
+}
+
+impl<T: YololOps, R: YololRange> WrappingAdd for YololNumber<T, R>
+{
+    /// Adds the numbers, wrapping around the bounds of a `YololNumber` instead of saturating.
+    fn wrapping_add(&self, other: &Self) -> Self
+    {
+        Self::wrapping_new(self.wrapping_inner().checked_add(other.wrapping_inner()))
+    }
+}
+
+impl<T: YololOps, R: YololRange> WrappingSub for YololNumber<T, R>
+{
+    /// Subtracts the numbers, wrapping around the bounds of a `YololNumber` instead of saturating.
+    fn wrapping_sub(&self, other: &Self) -> Self
+    {
+        Self::wrapping_new(self.wrapping_inner().checked_sub(other.wrapping_inner()))
+    }
+}
+
+impl<T: YololOps, R: YololRange> WrappingMul for YololNumber<T, R>
+{
+    /// Multiplies the numbers, wrapping around the bounds of a `YololNumber` instead of saturating.
+    /// Decimals past what a `YololNumber` can express are truncated before wrapping.
+    fn wrapping_mul(&self, other: &Self) -> Self
+    {
+        let product = self.wrapping_inner().checked_mul(other.wrapping_inner())
+            .map(|product| product / Self::wrapping_conversion());
+
+        Self::wrapping_new(product)
+    }
+}
+
+impl<T: YololOps, R: YololRange> CheckedNeg for YololNumber<T, R>
+{
+    /// Negates the number, failing if the result is outside of the bounds of a `YololNumber`.
+    fn checked_neg(&self) -> Option<Self>
+    {
+        T::zero().checked_sub(&self.0)
+            .and_then(|inner| Self::try_from_raw(RawInner(inner)).ok())
+    }
+}
+
+impl<T: YololOps + AsPrimitive<f64>, R: YololRange> Pow<YololNumber<T, R>> for YololNumber<T, R>
+where f64: AsPrimitive<T>
+{
+    type Output = Self;
+
+    fn pow(self, other: Self) -> Self
+    {
+        YololNumber::pow(self, other)
+    }
+}
+
+impl<T: YololOps, R: YololRange> Inv for YololNumber<T, R>
+{
+    type Output = Self;
+
+    /// Returns `1 / self`, which follows yolol division and is `0` when dividing by zero.
+    fn inv(self) -> Self
+    {
+        Self::one() / self
+    }
+}
+
+// The fused operations are done on the raw inners in the wide type, so nothing is lost until the very end.
+// They're for tooling that wants the intended result of a formula, scripts in game always use the chained operators.
+impl<T: YololOps, R: YololRange> YololNumber<T, R>
+{
+    /// Computes `(self * a) / b` as a single operation, truncating and saturating only the final result.
+    ///
+    /// The chained `self * a / b` truncates the product to 3 decimal places and saturates it before dividing,
+    /// so `0.001 * 0.5 / 0.5` is `0` and `max * 2 / 4` is a quarter of the max, where this gives `0.001`
+    /// and half of the max. Dividing by zero gives `0`, just like yolol division.
+    pub fn mul_div(self, a: Self, b: Self) -> Self
+    {
+        if b.is_zero()
+        {
+            return Self::zero();
+        }
+
+        // The product of the raw inners is already scaled up by the conversion value, which the division takes back out
+        let inner = self.0.widen().checked_mul(&a.0.widen())
+            .and_then(|product| product.checked_div(&b.0.widen()));
+
+        let negative = (self.is_negative() != a.is_negative()) != b.is_negative();
+        Self::saturating_from_wide(inner, negative)
+    }
+
+    /// Computes `(self * a) + b` as a single operation, truncating and saturating only the final result.
+    ///
+    /// The chained `self * a + b` truncates the product to 3 decimal places and saturates it before adding,
+    /// so `-0.5 * 0.001 + 0.001` is `0.001` and `max * 2 + min` is `-0.001`, where this gives `0` and a thousandth below the max.
+    /// It's named apart from `MulAdd::mul_add`, which is the chained version so generic code gets what yolol gives.
+    pub fn fused_mul_add(self, a: Self, b: Self) -> Self
+    {
+        let conversion = Self::wide_conversion_val();
+
+        // Shifting `b` up to the scale of the product means the sum only gets truncated once
+        let inner = self.0.widen().checked_mul(&a.0.widen())
+            .and_then(|product| product.checked_add(&b.0.widen().checked_mul(&conversion)?))
+            .and_then(|sum| sum.checked_div(&conversion));
+
+        // Only the product can overflow the wide type, so it decides which end to saturate at
+        let negative = self.is_negative() != a.is_negative();
+        Self::saturating_from_wide(inner, negative)
+    }
+
+    /// Narrows a wide raw inner back into a `YololNumber`, saturating if it doesn't fit or overflowed along the way.
+    fn saturating_from_wide(inner: Option<T::Wide>, negative: bool) -> Self
+    {
+        match inner.and_then(T::narrow)
+        {
+            Some(inner) => Self::from_raw(RawInner(inner)),
+
+            None if negative => Self::min_value(),
+            None => Self::max_value(),
+        }
+    }
+}
+
+impl<T: YololOps, R: YololRange> MulAdd for YololNumber<T, R>
+{
+    type Output = Self;
+
+    /// Computes `(self * a) + b` with the yolol operators, so each step is saturated and truncated.
+    /// See `YololNumber::fused_mul_add` to only do that to the final result.
+    fn mul_add(self, a: Self, b: Self) -> Self
+    {
+        (self * a) + b
+    }
+}
+
+impl<T: YololOps, R: YololRange> YololNumber<T, R>
+{
+    /// Gets the whole number euclidean quotient in the wide type and the remainder as a raw inner.
+    /// Returns `None` when dividing by zero.
+    fn raw_div_rem_euclid(self, other: Self) -> Option<(T::Wide, T)>
+    {
+        if other.0.is_zero()
+        {
+            return None;
+        }
+
+        // The quotient of the minimum by `-0.001` is past the top of the backing type when the range fills it,
+        // so it's done in the wide type, which the range invariants keep it from overflowing
+        let (left, right) = (self.0.widen(), other.0.widen());
+        let quotient = left / right;
+        let remainder = left % right;
+
+        // The remainder from `%` takes the sign of the dividend, so it needs to be shifted to be positive
+        let (quotient, remainder) = if remainder < T::Wide::zero()
+        {
+            if right > T::Wide::zero() {
+                (quotient - T::Wide::one(), remainder + right)
+            } else {
+                (quotient + T::Wide::one(), remainder - right)
+            }
+        }
+        else
+        {
+            (quotient, remainder)
+        };
+
+        let remainder = T::narrow(remainder)
+            .expect("[YololNumber::raw_div_rem_euclid] Remainder is smaller than the divisor, so it always fits!");
+
+        Some((quotient, remainder))
+    }
+
+    /// Returns the whole number `q` such that `self = (other * q) + r` where `0 <= r < |other|`.
+    /// Unlike `/`, the quotient is rounded so the remainder is never negative.
+    /// Dividing by zero gives `0`, just like yolol division, and the quotient saturates.
+    pub fn div_euclid(self, other: Self) -> Self
+    {
+        self.raw_div_rem_euclid(other)
+            .map_or_else(Self::zero, |(quotient, _)| {
+                let inner = quotient.checked_mul(&Self::wide_conversion_val());
+                Self::saturating_from_wide(inner, quotient.is_negative())
+            })
+    }
+
+    /// Returns the `r` such that `self = (other * q) + r` where `0 <= r < |other|`.
+    /// Unlike `%`, which takes the sign of `self`, the remainder is never negative.
+    /// Taking the remainder of zero gives `0`, just like yolol modulo.
+    pub fn rem_euclid(self, other: Self) -> Self
+    {
+        self.raw_div_rem_euclid(other)
+            .map_or_else(Self::zero, |(_, remainder)| YololNumber(remainder, PhantomData))
+    }
+}
+
+impl<T: YololOps, R: YololRange> Euclid for YololNumber<T, R>
+{
+    fn div_euclid(&self, other: &Self) -> Self
+    {
+        YololNumber::div_euclid(*self, *other)
+    }
+
+    fn rem_euclid(&self, other: &Self) -> Self
+    {
+        YololNumber::rem_euclid(*self, *other)
+    }
+}
+
+impl<T: YololOps, R: YololRange> CheckedEuclid for YololNumber<T, R>
+{
+    /// Fails when dividing by zero, or if the quotient is outside of the bounds of a `YololNumber`.
+    fn checked_div_euclid(&self, other: &Self) -> Option<Self>
+    {
+        let (quotient, _) = self.raw_div_rem_euclid(*other)?;
+        let inner = T::narrow(quotient.checked_mul(&Self::wide_conversion_val())?)?;
+
+        Self::try_from_raw(RawInner(inner)).ok()
+    }
+
+    /// Fails when taking the remainder of zero.
+    fn checked_rem_euclid(&self, other: &Self) -> Option<Self>
+    {
+        let (_, remainder) = self.raw_div_rem_euclid(*other)?;
+        Some(YololNumber(remainder, PhantomData))
+    }
+}
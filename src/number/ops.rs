@@ -1,30 +1,50 @@
-use std::cmp;
+use core::marker::PhantomData;
 
-use std::ops::{
+use core::cmp;
+
+use core::iter::{
+    Sum,
+    Product,
+};
+
+use core::ops::{
     Add,
     Sub,
     Mul,
     Div,
     Rem,
     Neg,
-    Not
+    Not,
+    AddAssign,
+    SubAssign,
+    MulAssign,
+    DivAssign,
+    RemAssign,
 };
 
 use num_traits::{
     AsPrimitive,
     Bounded,
+    CheckedMul,
+    CheckedDiv,
+    CheckedNeg,
+    Signed,
     Zero, One
 };
 
 use super::YololNumber;
+use super::raw_inner::RawInner;
+use super::report::{self, OpKind, OpReport};
 
+use crate::float_math;
 use crate::traits::{
     YololOps,
+    YololRange,
     ArgBounds,
 };
 
 // These ops internally use f64, so we need special trait bounds for them
-impl<T: YololOps + AsPrimitive<f64>> YololNumber<T>
+impl<T: YololOps + AsPrimitive<f64>, R: YololRange> YololNumber<T, R>
 where f64: AsPrimitive<T>
 {
     /// Converts the inner to a float and scales it into it's actual value range
@@ -38,68 +58,88 @@ where f64: AsPrimitive<T>
     /// Converts a float value into a `YololNumber` with correct rounding behaviour
     #[inline]
     pub fn from_float(input: f64) -> Self
+    {
+        Self::from_float_reporting(input).0
+    }
+
+    /// Converts a float value into a `YololNumber` with correct rounding behaviour, reporting
+    /// if the result saturated, had decimals rounded off, or was mapped from NaN.
+    pub fn from_float_reporting(input: f64) -> (Self, OpReport)
     {
         if input.is_nan()
         {
-            return YololNumber::min_value();
+            let report = OpReport { nan_mapped: true, ..OpReport::default() };
+            return report::observe(OpKind::Conversion, (YololNumber::min_value(), report));
         }
 
         let inner_float = input * Self::conversion_val::<f64>();
-        YololNumber(inner_float.round().as_()).bound()
+        let rounded = float_math::round(inner_float);
+
+        let max: f64 = Self::max_value().0.as_();
+        let min: f64 = Self::min_value().0.as_();
+
+        let output = if rounded > max
+        {
+            Self::saturate(true)
+        }
+        else if rounded < min
+        {
+            Self::saturate(false)
+        }
+        else
+        {
+            let report = OpReport::truncation(rounded != inner_float);
+            (YololNumber(rounded.as_(), PhantomData).bound(), report)
+        };
+
+        report::observe(OpKind::Conversion, output)
     }
 
     pub fn pow(self, other: Self) -> Self
     {
-        let pow = self.float_value()
-            .powf(other.float_value());
+        let pow = float_math::powf(self.float_value(), other.float_value());
 
         YololNumber::from_float(pow)
     }
 
     pub fn sqrt(self) -> Self
     {
-        let output = self.float_value().sqrt();
+        let output = float_math::sqrt(self.float_value());
         YololNumber::from_float(output)
     }
 
     pub fn sin(self) -> Self
     {
-        let rads = self.float_value().to_radians();
-        YololNumber::from_float(rads.sin())
+        YololNumber::from_float(float_math::sin_degrees(self.float_value()))
     }
 
     pub fn cos(self) -> Self
     {
-        let rads = self.float_value().to_radians();
-        YololNumber::from_float(rads.cos())
+        YololNumber::from_float(float_math::cos_degrees(self.float_value()))
     }
 
     pub fn tan(self) -> Self
     {
-        let rads = self.float_value().to_radians();
-        YololNumber::from_float(rads.tan())
+        YololNumber::from_float(float_math::tan_degrees(self.float_value()))
     }
 
     pub fn asin(self) -> Self
     {
-        let rads = self.float_value().asin();
-        YololNumber::from_float(rads.to_degrees())
+        YololNumber::from_float(float_math::asin_degrees(self.float_value()))
     }
 
     pub fn acos(self) -> Self
     {
-        let rads = self.float_value().acos();
-        YololNumber::from_float(rads.to_degrees())
+        YololNumber::from_float(float_math::acos_degrees(self.float_value()))
     }
 
     pub fn atan(self) -> Self
     {
-        let rads = self.float_value().atan();
-        YololNumber::from_float(rads.to_degrees())
+        YololNumber::from_float(float_math::atan_degrees(self.float_value()))
     }
 }
 
-impl<T: YololOps> YololNumber<T>
+impl<T: YololOps, R: YololRange> YololNumber<T, R>
 {
     pub fn floor(self) -> Self
     {
@@ -114,8 +154,13 @@ impl<T: YololOps> YololNumber<T>
         // Then we find out how far it is from 10
         let adjustment = Self::conversion_val() - first_decimal;
 
-        // Then by adding that adjustment, we bring us to the next whole value
-        YololNumber(self.0 + adjustment).bound()
+        // Then by adding that adjustment, we bring us to the next whole value.
+        // Past the top of the backing type is past the top of the range too, so that saturates
+        match self.0.checked_add(&adjustment)
+        {
+            Some(inner) => YololNumber(inner, PhantomData).bound(),
+            None => Self::saturate(true).0,
+        }
     }
 
     pub fn clamp(self, min: impl ArgBounds<T>, max: impl ArgBounds<T>) -> Self
@@ -125,13 +170,21 @@ impl<T: YololOps> YololNumber<T>
 
         num_traits::clamp(self, min, max).bound()
     }
+
+    /// Wraps the raw inner, failing if it's outside the bounds of a `YololNumber`.
+    /// This is what keeps the checked operations from going outside the Yolol range,
+    /// which the operators rely on to know when to saturate.
+    fn checked_new(inner: T) -> Option<Self>
+    {
+        Self::try_from_raw(RawInner(inner)).ok()
+    }
 }
 
-impl<T: YololOps> num_traits::Signed for YololNumber<T>
+impl<T: YololOps, R: YololRange> num_traits::Signed for YololNumber<T, R>
 {
     fn abs(&self) -> Self
     {
-        YololNumber(self.0.abs()).bound()
+        if self.is_negative() { -*self } else { *self }
     }
 
     fn abs_sub(&self, other: &Self) -> Self
@@ -165,9 +218,9 @@ impl<T: YololOps> num_traits::Signed for YololNumber<T>
     }
 }
 
-impl<T: YololOps> cmp::Eq for YololNumber<T> {}
+impl<T: YololOps, R: YololRange> cmp::Eq for YololNumber<T, R> {}
 
-impl<T: YololOps> cmp::PartialEq for YololNumber<T>
+impl<T: YololOps, R: YololRange> cmp::PartialEq for YololNumber<T, R>
 {
     fn eq(&self, other: &Self) -> bool
     {
@@ -175,15 +228,15 @@ impl<T: YololOps> cmp::PartialEq for YololNumber<T>
     }
 }
 
-impl<T: YololOps> cmp::Ord for YololNumber<T>
+impl<T: YololOps, R: YololRange> cmp::Ord for YololNumber<T, R>
 {
-    fn cmp(&self, other: &YololNumber<T>) -> cmp::Ordering
+    fn cmp(&self, other: &YololNumber<T, R>) -> cmp::Ordering
     {
         self.0.cmp(&other.0)
     }
 }
 
-impl<T: YololOps> cmp::PartialOrd for YololNumber<T>
+impl<T: YololOps, R: YololRange> cmp::PartialOrd for YololNumber<T, R>
 {
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering>
     {
@@ -191,113 +244,220 @@ impl<T: YololOps> cmp::PartialOrd for YololNumber<T>
     }
 }
 
-impl<T: YololOps> num_traits::CheckedAdd for YololNumber<T>
+impl<T: YololOps, R: YololRange> num_traits::CheckedAdd for YololNumber<T, R>
 {
+    /// Fails if the result is outside the bounds of a `YololNumber`, not just the bounds of the backing type.
     fn checked_add(&self, other: &Self) -> Option<Self>
     {
-        self.0.checked_add(&other.0).map(YololNumber)
+        self.0.checked_add(&other.0)
+            .and_then(Self::checked_new)
     }
 }
 
-impl<T: YololOps> num_traits::CheckedSub for YololNumber<T>
+impl<T: YololOps, R: YololRange> num_traits::CheckedSub for YololNumber<T, R>
 {
+    /// Fails if the result is outside the bounds of a `YololNumber`, not just the bounds of the backing type.
     fn checked_sub(&self, other: &Self) -> Option<Self>
     {
-        self.0.checked_sub(&other.0).map(YololNumber)
+        self.0.checked_sub(&other.0)
+            .and_then(Self::checked_new)
     }
 }
 
-impl<T: YololOps> num_traits::CheckedMul for YololNumber<T>
+impl<T: YololOps, R: YololRange> num_traits::CheckedMul for YololNumber<T, R>
 {
+    /// Fails if the result is outside the bounds of a `YololNumber`, not just the bounds of the backing type.
     fn checked_mul(&self, other: &Self) -> Option<Self>
     {
-        self.0.checked_mul(&other.0)?
-            .checked_div(&Self::conversion_val())
-            .map(YololNumber)
+        // The product of the raw inners is far larger than the result, so it's done in the wide type
+        let inner = self.0.widen().checked_mul(&other.0.widen())?
+            .checked_div(&Self::wide_conversion_val())?;
+
+        T::narrow(inner).and_then(Self::checked_new)
     }
 }
 
-impl<T: YololOps> num_traits::CheckedDiv for YololNumber<T>
+impl<T: YololOps, R: YololRange> num_traits::CheckedDiv for YololNumber<T, R>
 {
+    /// Fails if the result is outside the bounds of a `YololNumber`, not just the bounds of the backing type.
     fn checked_div(&self, other: &Self) -> Option<Self>
     {
-        self.0.checked_mul(&Self::conversion_val())?
-            .checked_div(&other.0)
-            .map(YololNumber)
+        // Shifting the dividend up can overflow the backing type, so it's done in the wide type
+        let inner = self.0.widen().checked_mul(&Self::wide_conversion_val())?
+            .checked_div(&other.0.widen())?;
+
+        T::narrow(inner).and_then(Self::checked_new)
     }
 }
 
-impl<T: YololOps> num_traits::CheckedRem for YololNumber<T>
+impl<T: YololOps, R: YololRange> num_traits::CheckedRem for YololNumber<T, R>
 {
+    /// Fails if the result is outside the bounds of a `YololNumber`, not just the bounds of the backing type.
     fn checked_rem(&self, other: &Self) -> Option<Self>
     {
-        self.0.checked_rem(&other.0).map(YololNumber)
+        // The remainder of the minimum by `-1` overflows when the range fills the backing type,
+        // even though the result is just zero, so it's done in the wide type
+        let inner = self.0.widen().checked_rem(&other.0.widen())?;
+
+        T::narrow(inner).and_then(Self::checked_new)
     }
 }
 
-impl<T: YololOps> Add for YololNumber<T>
+impl<T: YololOps, R: YololRange> Add for YololNumber<T, R>
 {
     type Output =  Self;
     fn add(self, other: Self) -> Self
     {
-        self.yolol_add(other)
+        self.add_reporting(other).0
     }
 }
-impl_for_refs!( impl<T: YololOps> Add for YololNumber<T> { fn add() -> Self } );
+impl_for_refs!( impl<T: YololOps, R: YololRange> Add for YololNumber<T, R> { fn add() -> Self } );
 
-impl<T: YololOps> Sub for YololNumber<T>
+impl<T: YololOps, R: YololRange> Sub for YololNumber<T, R>
 {
     type Output = Self;
     fn sub(self, other: Self) -> Self
     {
-        self.yolol_sub(other)
+        self.sub_reporting(other).0
     }
 }
-impl_for_refs!( impl<T: YololOps> Sub for YololNumber<T> { fn sub() -> Self } );
+impl_for_refs!( impl<T: YololOps, R: YololRange> Sub for YololNumber<T, R> { fn sub() -> Self } );
 
-impl<T: YololOps> Mul for YololNumber<T>
+impl<T: YololOps, R: YololRange> Mul for YololNumber<T, R>
 {
     type Output = Self;
     fn mul(self, other: Self) -> Self
     {
-        self.yolol_mul(other)
+        self.mul_reporting(other).0
     }
 }
-impl_for_refs!( impl<T: YololOps> Mul for YololNumber<T> { fn mul() -> Self } );
+impl_for_refs!( impl<T: YololOps, R: YololRange> Mul for YololNumber<T, R> { fn mul() -> Self } );
 
-impl<T: YololOps> Div for YololNumber<T>
+impl<T: YololOps, R: YololRange> Div for YololNumber<T, R>
 {
     type Output = Self;
 
     /// Performs yolol compliant division, but will return `0` in the case of error.
     fn div(self, other: Self) -> Self
     {
-        self.yolol_div(other)
-            .unwrap_or_else(YololNumber::zero)
+        self.div_reporting(other).0
     }
 }
-impl_for_refs!( impl<T: YololOps> Div for YololNumber<T> { fn div() -> Self } );
+impl_for_refs!( impl<T: YololOps, R: YololRange> Div for YololNumber<T, R> { fn div() -> Self } );
 
-impl<T: YololOps> Rem for YololNumber<T>
+impl<T: YololOps, R: YololRange> Rem for YololNumber<T, R>
 {
     type Output = Self;
+
+    /// Performs yolol compliant modulo, which is the exact remainder of truncated division.
+    /// The result takes the sign of `self`, so `-7 % 3` is `-1`, and decimals carry through, so `5.5 % 2` is `1.5`.
+    /// Will return `0` when taking the remainder of zero, see `rem_euclid` for a remainder that's never negative.
     fn rem(self, other: Self) -> Self
     {
-        self.yolol_mod(other)
+        self.rem_reporting(other).0
+    }
+}
+impl_for_refs!( impl<T: YololOps, R: YololRange> Rem for YololNumber<T, R> { fn rem() -> Self } );
+
+impl<T: YololOps, R: YololRange> AddAssign for YololNumber<T, R>
+{
+    fn add_assign(&mut self, other: Self)
+    {
+        *self = *self + other;
     }
 }
-impl_for_refs!( impl<T: YololOps> Rem for YololNumber<T> { fn rem() -> Self } );
+impl_assign_for_refs!( impl<T: YololOps, R: YololRange> AddAssign for YololNumber<T, R> { fn add_assign() -> Self } );
 
-impl<T: YololOps> Neg for YololNumber<T>
+impl<T: YololOps, R: YololRange> SubAssign for YololNumber<T, R>
+{
+    fn sub_assign(&mut self, other: Self)
+    {
+        *self = *self - other;
+    }
+}
+impl_assign_for_refs!( impl<T: YololOps, R: YololRange> SubAssign for YololNumber<T, R> { fn sub_assign() -> Self } );
+
+impl<T: YololOps, R: YololRange> MulAssign for YololNumber<T, R>
+{
+    fn mul_assign(&mut self, other: Self)
+    {
+        *self = *self * other;
+    }
+}
+impl_assign_for_refs!( impl<T: YololOps, R: YololRange> MulAssign for YololNumber<T, R> { fn mul_assign() -> Self } );
+
+impl<T: YololOps, R: YololRange> DivAssign for YololNumber<T, R>
+{
+    /// Performs yolol compliant division, but will assign `0` in the case of error.
+    fn div_assign(&mut self, other: Self)
+    {
+        *self = *self / other;
+    }
+}
+impl_assign_for_refs!( impl<T: YololOps, R: YololRange> DivAssign for YololNumber<T, R> { fn div_assign() -> Self } );
+
+impl<T: YololOps, R: YololRange> RemAssign for YololNumber<T, R>
+{
+    fn rem_assign(&mut self, other: Self)
+    {
+        *self = *self % other;
+    }
+}
+impl_assign_for_refs!( impl<T: YololOps, R: YololRange> RemAssign for YololNumber<T, R> { fn rem_assign() -> Self } );
+
+impl<T: YololOps, R: YololRange> Sum for YololNumber<T, R>
+{
+    /// Adds up the numbers one at a time, saturating at each step the same as a yolol loop would.
+    fn sum<I: Iterator<Item=Self>>(iter: I) -> Self
+    {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<'a, T: YololOps, R: YololRange> Sum<&'a YololNumber<T, R>> for YololNumber<T, R>
+{
+    /// Adds up the numbers one at a time, saturating at each step the same as a yolol loop would.
+    fn sum<I: Iterator<Item=&'a Self>>(iter: I) -> Self
+    {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<T: YololOps, R: YololRange> Product for YololNumber<T, R>
+{
+    /// Multiplies the numbers one at a time, saturating at each step the same as a yolol loop would.
+    fn product<I: Iterator<Item=Self>>(iter: I) -> Self
+    {
+        iter.fold(Self::one(), Mul::mul)
+    }
+}
+
+impl<'a, T: YololOps, R: YololRange> Product<&'a YololNumber<T, R>> for YololNumber<T, R>
+{
+    /// Multiplies the numbers one at a time, saturating at each step the same as a yolol loop would.
+    fn product<I: Iterator<Item=&'a Self>>(iter: I) -> Self
+    {
+        iter.fold(Self::one(), Mul::mul)
+    }
+}
+
+impl<T: YololOps, R: YololRange> Neg for YololNumber<T, R>
 {
     type Output = Self;
+    /// Negates the number, saturating if the negation is out of range, such as for the minimum value.
     fn neg(self) -> Self
     {
-        YololNumber(-self.0).bound()
+        match self.checked_neg()
+        {
+            Some(num) => num,
+
+            None if self.is_negative() => Self::max_value(),
+            None => Self::min_value(),
+        }
     }
 }
 
-impl<T: YololOps> Not for YololNumber<T>
+impl<T: YololOps, R: YololRange> Not for YololNumber<T, R>
 {
     type Output = Self;
     fn not(self) -> Self
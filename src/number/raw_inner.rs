@@ -0,0 +1,110 @@
+use num_traits::{
+    NumCast,
+    ToPrimitive,
+    FromPrimitive,
+};
+
+use super::YololNumber;
+
+use crate::traits::{YololOps, YololRange};
+
+/// A raw inner value of a `YololNumber`, meaning the value is larger by a factor
+/// of the conversion value than the number it expresses. The raw inner of `1.5` is `1500`.
+///
+/// Wrapping raw inners in this type keeps them from being mixed up with actual values,
+/// as a `YololNumber` will only go into or out of its raw inner through a `RawInner`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct RawInner<T>(pub T);
+
+impl<T> RawInner<T>
+{
+    /// Unwraps the raw inner value.
+    pub fn into_inner(self) -> T
+    {
+        self.0
+    }
+}
+
+impl<T: YololOps, R: YololRange> From<YololNumber<T, R>> for RawInner<T>
+{
+    fn from(input: YololNumber<T, R>) -> Self
+    {
+        input.to_raw()
+    }
+}
+
+impl<T: YololOps, R: YololRange> From<RawInner<T>> for YololNumber<T, R>
+{
+    /// Saturates at the bounds of a `YololNumber`.
+    fn from(input: RawInner<T>) -> Self
+    {
+        YololNumber::from_raw(input)
+    }
+}
+
+// The primitive traits for a `RawInner` are purely passthroughs to the wrapped
+// type, so they never do any scaling.
+
+impl<T: ToPrimitive> ToPrimitive for RawInner<T>
+{
+    fn to_i64(&self) -> Option<i64>
+    {
+        self.0.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64>
+    {
+        self.0.to_u64()
+    }
+
+    fn to_i128(&self) -> Option<i128>
+    {
+        self.0.to_i128()
+    }
+
+    fn to_u128(&self) -> Option<u128>
+    {
+        self.0.to_u128()
+    }
+
+    fn to_f64(&self) -> Option<f64>
+    {
+        self.0.to_f64()
+    }
+}
+
+impl<T: FromPrimitive> FromPrimitive for RawInner<T>
+{
+    fn from_i64(num: i64) -> Option<Self>
+    {
+        T::from_i64(num).map(RawInner)
+    }
+
+    fn from_u64(num: u64) -> Option<Self>
+    {
+        T::from_u64(num).map(RawInner)
+    }
+
+    fn from_i128(num: i128) -> Option<Self>
+    {
+        T::from_i128(num).map(RawInner)
+    }
+
+    fn from_u128(num: u128) -> Option<Self>
+    {
+        T::from_u128(num).map(RawInner)
+    }
+
+    fn from_f64(num: f64) -> Option<Self>
+    {
+        T::from_f64(num).map(RawInner)
+    }
+}
+
+impl<T: NumCast> NumCast for RawInner<T>
+{
+    fn from<F: ToPrimitive>(input: F) -> Option<Self>
+    {
+        T::from(input).map(RawInner)
+    }
+}
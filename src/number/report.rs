@@ -0,0 +1,276 @@
+#[cfg(feature = "std")]
+use std::{boxed::Box, cell::RefCell};
+
+use core::ops::{BitOr, BitOrAssign};
+
+use num_traits::{
+    Bounded,
+    CheckedAdd,
+    CheckedSub,
+    CheckedMul,
+    CheckedDiv,
+    CheckedRem,
+    Signed,
+    Zero,
+};
+
+use super::YololNumber;
+
+use crate::traits::{YololOps, YololRange};
+
+/// The kind of operation an event was reported from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OpKind
+{
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+    /// Creating a `YololNumber` from a value, raw inner or float.
+    Conversion,
+    /// Parsing a `YololNumber` from a string.
+    Parse,
+}
+
+/// Flags for everything an operation did to make its result differ from the exact result.
+/// A report with no flags set means the result is exact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct OpReport
+{
+    /// The result was too large, and was clamped to the maximum value.
+    pub saturated_high: bool,
+    /// The result was too small, and was clamped to the minimum value.
+    pub saturated_low: bool,
+    /// Digits past what a `YololNumber` can express were dropped.
+    pub truncated: bool,
+    /// A NaN input was turned into the minimum value.
+    pub nan_mapped: bool,
+    /// Dividing or taking the modulo by zero was turned into a result of zero.
+    pub div_by_zero_mapped: bool,
+}
+
+impl OpReport
+{
+    /// Returns whether the result was exact, with no flags set.
+    pub fn is_clean(&self) -> bool
+    {
+        *self == OpReport::default()
+    }
+
+    /// Returns whether the result was clamped to either bound.
+    pub fn saturated(&self) -> bool
+    {
+        self.saturated_high || self.saturated_low
+    }
+
+    /// A report for a result clamped to the maximum value if `high`, otherwise the minimum value.
+    pub(crate) fn saturation(high: bool) -> Self
+    {
+        OpReport { saturated_high: high, saturated_low: !high, ..OpReport::default() }
+    }
+
+    /// A report that's only flagged as truncated if `truncated` is true.
+    pub(crate) fn truncation(truncated: bool) -> Self
+    {
+        OpReport { truncated, ..OpReport::default() }
+    }
+}
+
+impl BitOr for OpReport
+{
+    type Output = Self;
+
+    /// Combines the reports of two steps, keeping every flag set in either.
+    fn bitor(self, other: Self) -> Self
+    {
+        OpReport {
+            saturated_high: self.saturated_high || other.saturated_high,
+            saturated_low: self.saturated_low || other.saturated_low,
+            truncated: self.truncated || other.truncated,
+            nan_mapped: self.nan_mapped || other.nan_mapped,
+            div_by_zero_mapped: self.div_by_zero_mapped || other.div_by_zero_mapped,
+        }
+    }
+}
+
+impl BitOrAssign for OpReport
+{
+    fn bitor_assign(&mut self, other: Self)
+    {
+        *self = *self | other;
+    }
+}
+
+#[cfg(feature = "std")]
+type Observer = Box<dyn Fn(OpKind, OpReport)>;
+
+#[cfg(feature = "std")]
+thread_local! {
+    static OBSERVER: RefCell<Option<Observer>> = RefCell::new(None);
+}
+
+/// Sets the observer for the current thread, replacing any previous one.
+/// It gets called with every report that has a flag set, from both the operators and the `*_reporting` functions.
+///
+/// # Panics
+/// The observer can use `YololNumber`s freely, but setting or clearing the observer from inside it will panic.
+#[cfg(feature = "std")]
+pub fn set_observer(observer: impl Fn(OpKind, OpReport) + 'static)
+{
+    OBSERVER.with(|cell| *cell.borrow_mut() = Some(Box::new(observer)));
+}
+
+/// Removes the observer for the current thread, if there is one.
+#[cfg(feature = "std")]
+pub fn clear_observer()
+{
+    OBSERVER.with(|cell| *cell.borrow_mut() = None);
+}
+
+/// Passes the report to the observer of the current thread, if it has a flag set.
+#[cfg(feature = "std")]
+pub(crate) fn notify(kind: OpKind, report: OpReport)
+{
+    // Most operations are exact, so this keeps them from touching the thread local at all
+    if report.is_clean()
+    {
+        return;
+    }
+
+    OBSERVER.with(|cell| {
+        if let Some(observer) = cell.borrow().as_ref()
+        {
+            observer(kind, report);
+        }
+    });
+}
+
+/// Without std there are no thread locals to keep an observer in, so there's nothing to notify.
+#[cfg(not(feature = "std"))]
+pub(crate) fn notify(_kind: OpKind, _report: OpReport) {}
+
+/// Notifies the observer about the output, then passes it through.
+pub(crate) fn observe<N>(kind: OpKind, output: (N, OpReport)) -> (N, OpReport)
+{
+    notify(kind, output.1);
+    output
+}
+
+impl<T: YololOps, R: YololRange> YololNumber<T, R>
+{
+    /// Adds the numbers, reporting if the result saturated.
+    pub fn add_reporting(self, other: Self) -> (Self, OpReport)
+    {
+        observe(OpKind::Add, self.add_with_report(other))
+    }
+
+    /// Subtracts the numbers, reporting if the result saturated.
+    pub fn sub_reporting(self, other: Self) -> (Self, OpReport)
+    {
+        observe(OpKind::Sub, self.sub_with_report(other))
+    }
+
+    /// Multiplies the numbers, reporting if the result saturated or had decimals truncated.
+    pub fn mul_reporting(self, other: Self) -> (Self, OpReport)
+    {
+        observe(OpKind::Mul, self.mul_with_report(other))
+    }
+
+    /// Divides the numbers, reporting if the result saturated, had decimals
+    /// truncated, or was mapped to zero from dividing by zero.
+    pub fn div_reporting(self, other: Self) -> (Self, OpReport)
+    {
+        observe(OpKind::Div, self.div_with_report(other))
+    }
+
+    /// Takes the modulo of the numbers, reporting if the result was mapped to zero from dividing by zero.
+    /// The remainder is always exact, so it never saturates or truncates.
+    pub fn rem_reporting(self, other: Self) -> (Self, OpReport)
+    {
+        let output = if other.is_zero()
+        {
+            Self::divided_by_zero_reporting()
+        }
+        else
+        {
+            (self.checked_rem(&other).unwrap_or_else(Self::zero), OpReport::default())
+        };
+
+        observe(OpKind::Mod, output)
+    }
+
+    /// Adds the numbers the same as `add_reporting`, without telling the observer.
+    pub(crate) fn add_with_report(self, other: Self) -> (Self, OpReport)
+    {
+        match self.checked_add(&other)
+        {
+            Some(num) => (num, OpReport::default()),
+            None => Self::saturate(!other.is_negative()),
+        }
+    }
+
+    /// Subtracts the numbers the same as `sub_reporting`, without telling the observer.
+    pub(crate) fn sub_with_report(self, other: Self) -> (Self, OpReport)
+    {
+        match self.checked_sub(&other)
+        {
+            Some(num) => (num, OpReport::default()),
+            None => Self::saturate(other.is_negative()),
+        }
+    }
+
+    /// Multiplies the numbers the same as `mul_reporting`, without telling the observer.
+    pub(crate) fn mul_with_report(self, other: Self) -> (Self, OpReport)
+    {
+        match self.checked_mul(&other)
+        {
+            Some(num) => {
+                // Any remainder from shifting the product back down is what got truncated
+                let truncated = self.0.widen().checked_mul(&other.0.widen())
+                    .and_then(|product| product.checked_rem(&Self::wide_conversion_val()))
+                    .is_some_and(|remainder| !remainder.is_zero());
+
+                (num, OpReport::truncation(truncated))
+            },
+
+            None => Self::saturate(self.is_negative() == other.is_negative()),
+        }
+    }
+
+    /// Divides the numbers the same as `div_reporting`, without telling the observer.
+    pub(crate) fn div_with_report(self, other: Self) -> (Self, OpReport)
+    {
+        if other.is_zero()
+        {
+            Self::divided_by_zero_reporting()
+        }
+        else
+        {
+            match self.checked_div(&other)
+            {
+                Some(num) => {
+                    let truncated = self.0.widen().checked_mul(&Self::wide_conversion_val())
+                        .and_then(|shifted| shifted.checked_rem(&other.0.widen()))
+                        .is_some_and(|remainder| !remainder.is_zero());
+
+                    (num, OpReport::truncation(truncated))
+                },
+
+                None => Self::saturate(self.is_negative() == other.is_negative()),
+            }
+        }
+    }
+
+    /// Returns the maximum value if `high`, otherwise the minimum value, along with the matching report.
+    pub(crate) fn saturate(high: bool) -> (Self, OpReport)
+    {
+        let num = if high { Self::max_value() } else { Self::min_value() };
+        (num, OpReport::saturation(high))
+    }
+
+    fn divided_by_zero_reporting() -> (Self, OpReport)
+    {
+        (Self::zero(), OpReport { div_by_zero_mapped: true, ..OpReport::default() })
+    }
+}
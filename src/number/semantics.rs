@@ -0,0 +1,107 @@
+use num_traits::Zero;
+
+use super::YololNumber;
+
+use crate::traits::{YololOps, YololRange};
+
+/// What happens when dividing or taking the modulo by zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DivideByZero
+{
+    /// The result is zero, which is what the `/` and `%` operators do.
+    Zero,
+    /// The operation fails, which a script treats as a runtime error.
+    Error,
+}
+
+/// A set of behaviours for the parts of yolol numbers that differ between the ways of running a script.
+/// Pick one at runtime and pass it to the `*_in` operations on a `YololNumber`, such as `YololNumber::add_in`.
+///
+/// The presets are named for what they model rather than for a patch number. There's no reliable record of
+/// which patch changed which behaviour, so only behaviours with a known use are modelled: overflow saturates,
+/// modulo takes the sign of the left side and trig is computed in an `f64` under every preset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Semantics
+{
+    pub divide_by_zero: DivideByZero,
+}
+
+impl Semantics
+{
+    /// The same behaviour as the regular operators and methods on a `YololNumber`, which is what this crate has
+    /// always modelled. It matches the live game except for dividing by zero, where it gives zero instead of
+    /// raising a runtime error, which suits tools that evaluate expressions outside of a running script.
+    pub const STANDARD: Semantics = Semantics {
+        divide_by_zero: DivideByZero::Zero,
+    };
+
+    /// The live game, where dividing by zero is a runtime error that skips the rest of the line.
+    /// Otherwise the same as the standard behaviour.
+    pub const STRICT: Semantics = Semantics {
+        divide_by_zero: DivideByZero::Error,
+    };
+}
+
+impl Default for Semantics
+{
+    fn default() -> Self
+    {
+        Semantics::STANDARD
+    }
+}
+
+// Adding, subtracting and multiplying are the same under every preset, but taking the semantics
+// lets an evaluator run every operation through the one it picked.
+impl<T: YololOps, R: YololRange> YololNumber<T, R>
+{
+    /// Adds the numbers following the given semantics.
+    pub fn add_in(_semantics: &Semantics, left: Self, right: Self) -> Self
+    {
+        left + right
+    }
+
+    /// Subtracts the numbers following the given semantics.
+    pub fn sub_in(_semantics: &Semantics, left: Self, right: Self) -> Self
+    {
+        left - right
+    }
+
+    /// Multiplies the numbers following the given semantics.
+    pub fn mul_in(_semantics: &Semantics, left: Self, right: Self) -> Self
+    {
+        left * right
+    }
+
+    /// Divides the numbers following the given semantics.
+    /// Returns `None` if dividing by zero is an error under the semantics.
+    pub fn div_in(semantics: &Semantics, left: Self, right: Self) -> Option<Self>
+    {
+        if right.is_zero()
+        {
+            return Self::divided_by_zero(semantics);
+        }
+
+        Some(left / right)
+    }
+
+    /// Takes the modulo of the numbers following the given semantics.
+    /// Returns `None` if dividing by zero is an error under the semantics.
+    pub fn mod_in(semantics: &Semantics, left: Self, right: Self) -> Option<Self>
+    {
+        if right.is_zero()
+        {
+            return Self::divided_by_zero(semantics);
+        }
+
+        Some(left % right)
+    }
+
+    fn divided_by_zero(semantics: &Semantics) -> Option<Self>
+    {
+        match semantics.divide_by_zero
+        {
+            DivideByZero::Zero => Some(Self::zero()),
+            DivideByZero::Error => None,
+        }
+    }
+}
@@ -1,27 +1,29 @@
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 use serde::{Serialize, Deserialize, Serializer, Deserializer, de::Visitor};
 
 use super::YololNumber;
-use crate::traits::YololOps;
+use super::conversions::FORMAT_BUFFER_LEN;
+use crate::traits::{YololOps, YololRange};
 
 // Because expressing these values is... complicated... the serialization standard
 // is to have them represented purely as a string.
-impl<T: YololOps> Serialize for YololNumber<T>
+impl<T: YololOps, R: YololRange> Serialize for YololNumber<T, R>
 {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
     {
-        serializer.serialize_str(&self.to_string())
+        let mut buf = [0_u8; FORMAT_BUFFER_LEN];
+        serializer.serialize_str(self.format_into(&mut buf))
     }
 }
 
-struct YololNumberVisitor<T: YololOps>(PhantomData<T>);
+struct YololNumberVisitor<T: YololOps, R: YololRange>(PhantomData<(T, R)>);
 
-impl<'de, T: YololOps> Visitor<'de> for YololNumberVisitor<T>
+impl<'de, T: YololOps, R: YololRange> Visitor<'de> for YololNumberVisitor<T, R>
 {
-    type Value = YololNumber<T>;
+    type Value = YololNumber<T, R>;
 
-    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result
     {
         write!(f, "a string containing only numerical characters, possibly with a decimal point")
     }
@@ -29,15 +31,12 @@ impl<'de, T: YololOps> Visitor<'de> for YololNumberVisitor<T>
     fn visit_str<E>(self, input: &str) -> Result<Self::Value, E>
     where E: serde::de::Error
     {
-        match input.parse::<YololNumber<T>>()
-        {
-            Ok(num) => Ok(num),
-            Err(error) => Err(E::custom(error))
-        }
+        input.parse::<YololNumber<T, R>>()
+            .map_err(E::custom)
     }
 }
 
-impl<'de, T: YololOps> Deserialize<'de> for YololNumber<T>
+impl<'de, T: YololOps, R: YololRange> Deserialize<'de> for YololNumber<T, R>
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where D: Deserializer<'de>
@@ -0,0 +1,223 @@
+use core::fmt;
+
+use core::ops::{
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Neg,
+};
+
+use num_traits::AsPrimitive;
+
+use super::YololNumber;
+
+use crate::float_math;
+use crate::traits::{YololOps, YololRange, StandardRange};
+
+/// A `YololNumber` paired with the ideal `f64` result of the same math, to measure how much error the
+/// truncation to 3 decimal places and the saturation at the bounds add to a formula.
+///
+/// Every operation is done on both, and the largest error seen at any step leading up to a result is kept,
+/// so an error that happened partway through a formula isn't hidden by later steps. Dividing by zero gives
+/// an infinite ideal where the `YololNumber` gives zero, which counts as an infinite error.
+#[derive(Debug, Clone, Copy)]
+pub struct Shadowed<T: YololOps, R: YololRange = StandardRange>
+{
+    value: YololNumber<T, R>,
+    ideal: f64,
+    max_abs_error: f64,
+    max_rel_error: f64,
+}
+
+impl<T: YololOps + AsPrimitive<f64>, R: YololRange> Shadowed<T, R>
+where f64: AsPrimitive<T>
+{
+    /// Starts tracking from a `YololNumber`, which is taken to be exact.
+    pub fn new(value: YololNumber<T, R>) -> Self
+    {
+        Shadowed {
+            value,
+            ideal: value.float_value(),
+            max_abs_error: 0.0,
+            max_rel_error: 0.0,
+        }
+    }
+
+    /// Starts tracking from a float, so any error from converting it into a `YololNumber` is counted.
+    pub fn from_float(input: f64) -> Self
+    {
+        Self::step(YololNumber::from_float(input), input, &[])
+    }
+
+    /// Returns the result of the math as it would happen in yolol.
+    pub fn value(self) -> YololNumber<T, R>
+    {
+        self.value
+    }
+
+    /// Returns the ideal result of the math, as done in an `f64`.
+    pub fn ideal(self) -> f64
+    {
+        self.ideal
+    }
+
+    /// Returns the absolute difference between the yolol result and the ideal result.
+    pub fn abs_error(self) -> f64
+    {
+        Self::abs_error_of(self.value, self.ideal)
+    }
+
+    /// Returns the absolute error relative to the size of the ideal result.
+    pub fn rel_error(self) -> f64
+    {
+        Self::rel_error_of(self.abs_error(), self.ideal)
+    }
+
+    /// Returns the largest absolute error of this result or any step leading up to it.
+    pub fn max_abs_error(self) -> f64
+    {
+        self.max_abs_error
+    }
+
+    /// Returns the largest relative error of this result or any step leading up to it.
+    pub fn max_rel_error(self) -> f64
+    {
+        self.max_rel_error
+    }
+
+    pub fn pow(self, other: Self) -> Self
+    {
+        Self::step(self.value.pow(other.value), float_math::powf(self.ideal, other.ideal), &[self, other])
+    }
+
+    pub fn sqrt(self) -> Self
+    {
+        Self::step(self.value.sqrt(), float_math::sqrt(self.ideal), &[self])
+    }
+
+    /// Takes the sine of the number in degrees.
+    pub fn sin(self) -> Self
+    {
+        Self::step(self.value.sin(), float_math::sin_degrees(self.ideal), &[self])
+    }
+
+    /// Takes the cosine of the number in degrees.
+    pub fn cos(self) -> Self
+    {
+        Self::step(self.value.cos(), float_math::cos_degrees(self.ideal), &[self])
+    }
+
+    /// Takes the tangent of the number in degrees.
+    pub fn tan(self) -> Self
+    {
+        Self::step(self.value.tan(), float_math::tan_degrees(self.ideal), &[self])
+    }
+
+    /// Takes the arcsine of the number in degrees.
+    pub fn asin(self) -> Self
+    {
+        Self::step(self.value.asin(), float_math::asin_degrees(self.ideal), &[self])
+    }
+
+    /// Takes the arccosine of the number in degrees.
+    pub fn acos(self) -> Self
+    {
+        Self::step(self.value.acos(), float_math::acos_degrees(self.ideal), &[self])
+    }
+
+    /// Takes the arctangent of the number in degrees.
+    pub fn atan(self) -> Self
+    {
+        Self::step(self.value.atan(), float_math::atan_degrees(self.ideal), &[self])
+    }
+
+    /// Builds the result of a step, carrying the largest errors of its inputs forward.
+    fn step(value: YololNumber<T, R>, ideal: f64, inputs: &[Self]) -> Self
+    {
+        let abs_error = Self::abs_error_of(value, ideal);
+        let rel_error = Self::rel_error_of(abs_error, ideal);
+
+        inputs.iter().fold(
+            Shadowed { value, ideal, max_abs_error: abs_error, max_rel_error: rel_error },
+            |out, input| Shadowed {
+                max_abs_error: out.max_abs_error.max(input.max_abs_error),
+                max_rel_error: out.max_rel_error.max(input.max_rel_error),
+                ..out
+            }
+        )
+    }
+
+    fn abs_error_of(value: YololNumber<T, R>, ideal: f64) -> f64
+    {
+        let error = (value.float_value() - ideal).abs();
+
+        // A NaN ideal means the math has no real answer, which `max` would otherwise quietly skip over
+        if error.is_nan() { f64::INFINITY } else { error }
+    }
+
+    fn rel_error_of(abs_error: f64, ideal: f64) -> f64
+    {
+        if abs_error == 0.0
+        {
+            return 0.0;
+        }
+
+        // An infinite error on an infinite ideal is still an infinite error, rather than NaN
+        let error = abs_error / ideal.abs();
+        if error.is_nan() { f64::INFINITY } else { error }
+    }
+}
+
+impl<T: YololOps + AsPrimitive<f64>, R: YololRange> From<YololNumber<T, R>> for Shadowed<T, R>
+where f64: AsPrimitive<T>
+{
+    fn from(value: YololNumber<T, R>) -> Self
+    {
+        Shadowed::new(value)
+    }
+}
+
+impl<T: YololOps + AsPrimitive<f64>, R: YololRange> fmt::Display for Shadowed<T, R>
+where f64: AsPrimitive<T>
+{
+    /// Shows the yolol result followed by the ideal result, like `0.333 (ideal 0.3333333333333333)`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{} (ideal {})", self.value, self.ideal)
+    }
+}
+
+/// Implements an operator on a `Shadowed`, doing the yolol and ideal math side by side.
+macro_rules! impl_shadowed_op {
+    ( $imp:ident, $method:ident, $ideal:expr ) => {
+        impl<T: YololOps + AsPrimitive<f64>, R: YololRange> $imp for Shadowed<T, R>
+        where f64: AsPrimitive<T>
+        {
+            type Output = Self;
+            fn $method(self, other: Self) -> Self
+            {
+                let ideal: fn(f64, f64) -> f64 = $ideal;
+                Self::step($imp::$method(self.value, other.value), ideal(self.ideal, other.ideal), &[self, other])
+            }
+        }
+        impl_for_refs!( impl<T: YololOps, R: YololRange> $imp for Shadowed<T, R> where { T: AsPrimitive<f64>, f64: AsPrimitive<T> } { fn $method() -> Self } );
+    }
+}
+
+impl_shadowed_op!(Add, add, |a, b| a + b);
+impl_shadowed_op!(Sub, sub, |a, b| a - b);
+impl_shadowed_op!(Mul, mul, |a, b| a * b);
+impl_shadowed_op!(Div, div, |a, b| a / b);
+impl_shadowed_op!(Rem, rem, |a, b| a % b);
+
+impl<T: YololOps + AsPrimitive<f64>, R: YololRange> Neg for Shadowed<T, R>
+where f64: AsPrimitive<T>
+{
+    type Output = Self;
+    fn neg(self) -> Self
+    {
+        Self::step(-self.value, -self.ideal, &[self])
+    }
+}
@@ -0,0 +1,52 @@
+use num_traits::Bounded;
+
+use super::YololNumber;
+use super::conversions::error::ConversionError;
+
+use crate::traits::{YololOps, YololRange};
+
+// These all rely on `YololNumber` being `repr(transparent)` over its inner type,
+// which makes a slice of one the exact same memory as a slice of the other.
+
+impl<T: YololOps, R: YololRange> YololNumber<T, R>
+{
+    /// Views a slice of raw inners as a slice of `YololNumber`s without copying.
+    /// Fails if any of the raw inners are outside the range of a `YololNumber`.
+    pub fn try_from_raw_slice(raw: &[T]) -> Result<&[Self], ConversionError>
+    {
+        Self::check_raw_slice(raw)?;
+
+        // SAFETY: `YololNumber<T, R>` is `repr(transparent)` over `T`, and every value was checked to be in range
+        Ok(unsafe { &*(raw as *const [T] as *const [Self]) })
+    }
+
+    /// Views a mutable slice of raw inners as a mutable slice of `YololNumber`s without copying.
+    /// Fails if any of the raw inners are outside the range of a `YololNumber`.
+    pub fn try_from_raw_slice_mut(raw: &mut [T]) -> Result<&mut [Self], ConversionError>
+    {
+        Self::check_raw_slice(raw)?;
+
+        // SAFETY: `YololNumber<T, R>` is `repr(transparent)` over `T`, and every value was checked to be in range
+        Ok(unsafe { &mut *(raw as *mut [T] as *mut [Self]) })
+    }
+
+    /// Views a slice of `YololNumber`s as a slice of their raw inners without copying.
+    pub fn as_raw_slice(nums: &[Self]) -> &[T]
+    {
+        // SAFETY: `YololNumber<T, R>` is `repr(transparent)` over `T`, and any value is a valid `T`
+        unsafe { &*(nums as *const [Self] as *const [T]) }
+    }
+
+    /// Fails on the first raw inner that's outside the range of a `YololNumber`.
+    fn check_raw_slice(raw: &[T]) -> Result<(), ConversionError>
+    {
+        let min = Self::min_value().0;
+        let max = Self::max_value().0;
+
+        match raw.iter().find(|&&inner| inner < min || inner > max)
+        {
+            Some(&inner) => Err(ConversionError::out_of_range(inner.is_negative())),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::RangeInclusive;
+
+use crate::YololNumber;
+
+/// The ways setting up a `Packer` or packing values with one can fail.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PackError
+{
+    /// The range of the field with this index has no values in it.
+    EmptyRange(usize),
+    /// The fields have more combinations than the whole numbers a Yolol number can hold,
+    /// or the range of a field goes past what a Yolol number can hold.
+    TooLarge,
+    /// The value for the field with this index isn't a whole number in the field's range.
+    OutOfRange(usize),
+}
+
+impl fmt::Display for PackError
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        match self
+        {
+            PackError::EmptyRange(index) => write!(f, "Field {} has an empty range.", index),
+            PackError::TooLarge => f.write_str("The fields have too many combinations, or too large of values, to fit in a Yolol number."),
+            PackError::OutOfRange(index) => write!(f, "The value for field {} isn't a whole number in its range.", index),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PackError {}
+
+/// Packs several whole number fields into a single `YololNumber`, the way players do to save on variables.
+///
+/// Each field is stored as its offset from the bottom of its range, multiplied by the number of combinations
+/// of the fields before it. So with fields of `0..=4` and `0..=11`, the first is multiplied by `1` and the
+/// second by `5`, and the largest packed number is `59`. Unpacking takes each field back out with only `%`,
+/// `-` and `/` on whole numbers, which are exact in yolol, and `pack_source`/`unpack_source` give the same math
+/// as yolol code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Packer<const N: usize>
+{
+    /// The bottom of each field's range.
+    mins: [i64; N],
+    /// The number of values in each field's range.
+    sizes: [i64; N],
+    /// What each field is multiplied by when packed.
+    weights: [i64; N],
+}
+
+impl<const N: usize> Packer<N>
+{
+    /// Lays out the fields in order, with the first field in the lowest place. Fails if a range is empty,
+    /// or if the packed numbers or the values of a field wouldn't fit in a `YololNumber`.
+    pub fn new(ranges: [RangeInclusive<i64>; N]) -> Result<Self, PackError>
+    {
+        // The packed numbers are whole, so the decimal places aren't usable
+        let max_whole = YololNumber::MAX.to_raw().0 / YololNumber::conversion_val::<i128>();
+        let min_whole = YololNumber::MIN.to_raw().0 / YololNumber::conversion_val::<i128>();
+
+        let mut mins = [0; N];
+        let mut sizes = [0; N];
+        let mut weights = [0; N];
+        let mut combinations: i128 = 1;
+
+        for (index, range) in ranges.iter().enumerate()
+        {
+            let (min, max) = (*range.start(), *range.end());
+            if min > max
+            {
+                return Err(PackError::EmptyRange(index));
+            }
+
+            if i128::from(min) < min_whole || i128::from(max) > max_whole
+            {
+                return Err(PackError::TooLarge);
+            }
+
+            let size = i128::from(max) - i128::from(min) + 1;
+
+            mins[index] = min;
+            weights[index] = combinations as i64;
+            sizes[index] = i64::try_from(size).map_err(|_| PackError::TooLarge)?;
+
+            combinations = combinations.checked_mul(size)
+                .filter(|&combinations| combinations - 1 <= max_whole)
+                .ok_or(PackError::TooLarge)?;
+        }
+
+        Ok(Packer { mins, sizes, weights })
+    }
+
+    /// The largest number packing can give, which is when every field is at the top of its range.
+    pub fn max_packed(&self) -> YololNumber
+    {
+        let combinations = self.sizes.iter().fold(1, |product, &size| product * i128::from(size));
+        YololNumber::from_value(combinations - 1)
+    }
+
+    /// Packs the values into one number, the same as the yolol code from `pack_source`.
+    /// Fails if a value isn't a whole number in the range of its field.
+    pub fn pack(&self, values: [YololNumber; N]) -> Result<YololNumber, PackError>
+    {
+        let mut packed = YololNumber::ZERO;
+
+        for (index, &value) in values.iter().enumerate()
+        {
+            let offset = value - YololNumber::from_value(self.mins[index]);
+
+            let in_range = offset.to_raw().0 % YololNumber::conversion_val::<i128>() == 0
+                && offset >= YololNumber::ZERO
+                && offset < YololNumber::from_value(self.sizes[index]);
+
+            if !in_range
+            {
+                return Err(PackError::OutOfRange(index));
+            }
+
+            packed += offset * YololNumber::from_value(self.weights[index]);
+        }
+
+        Ok(packed)
+    }
+
+    /// Unpacks every field, the same as the yolol code from `unpack_source`.
+    pub fn unpack(&self, packed: YololNumber) -> [YololNumber; N]
+    {
+        let mut values = [YololNumber::ZERO; N];
+
+        for (index, value) in values.iter_mut().enumerate()
+        {
+            *value = self.unpack_field(packed, index);
+        }
+
+        values
+    }
+
+    /// Unpacks the field with the given index, the same as the yolol code from `unpack_source`.
+    /// Panics if the index is out of bounds.
+    pub fn unpack_field(&self, packed: YololNumber, index: usize) -> YololNumber
+    {
+        let weight = YololNumber::from_value(self.weights[index]);
+
+        // Everything below this field is removed by the modulo of its weight, and everything above it by the
+        // modulo of the next weight. The ends skip whichever one is a no-op, which also keeps the modulo of the
+        // last field from needing the full number of combinations, since that can be just past the range.
+        let above_removed = if index + 1 < N { packed % (weight * YololNumber::from_value(self.sizes[index])) } else { packed };
+        let offset = if index == 0 { above_removed } else { (above_removed - packed % weight) / weight };
+
+        offset + YololNumber::from_value(self.mins[index])
+    }
+
+    /// Gives the yolol code that packs the field variables into the `packed` variable, like `a=x+y*5`.
+    #[cfg(feature = "std")]
+    pub fn pack_source(&self, packed: &str, fields: [&str; N]) -> String
+    {
+        let mut source = format!("{}=", packed);
+
+        if N == 0
+        {
+            source.push('0');
+        }
+
+        for (index, field) in fields.iter().enumerate()
+        {
+            if index > 0
+            {
+                source.push('+');
+            }
+
+            let min = self.mins[index];
+            let weight = self.weights[index];
+
+            let offset = match min
+            {
+                0 => String::from(*field),
+                min if min > 0 => format!("{}-{}", field, min),
+                min => format!("{}+{}", field, literal(-i128::from(min))),
+            };
+
+            if weight == 1
+            {
+                source.push_str(&offset);
+            }
+            else if min == 0
+            {
+                source.push_str(&format!("{}*{}", offset, literal(weight)));
+            }
+            else
+            {
+                source.push_str(&format!("({})*{}", offset, literal(weight)));
+            }
+        }
+
+        source
+    }
+
+    /// Gives the yolol code that unpacks the `packed` variable into the field variables,
+    /// like `x=a%5 y=(a-a%5)/5`. Each field is its own statement, separated by spaces.
+    #[cfg(feature = "std")]
+    pub fn unpack_source(&self, packed: &str, fields: [&str; N]) -> String
+    {
+        let statements = fields.iter().enumerate().map(|(index, field)| {
+            let weight = self.weights[index];
+
+            let above_removed = if index + 1 < N
+            {
+                format!("{}%{}", packed, literal(weight * self.sizes[index]))
+            }
+            else
+            {
+                String::from(packed)
+            };
+
+            let offset = if index == 0
+            {
+                above_removed
+            }
+            else
+            {
+                format!("({}-{}%{})/{}", above_removed, packed, literal(weight), literal(weight))
+            };
+
+            match self.mins[index]
+            {
+                0 => format!("{}={}", field, offset),
+                min if min > 0 => format!("{}={}+{}", field, offset, literal(min)),
+                min => format!("{}={}-{}", field, offset, literal(-i128::from(min))),
+            }
+        });
+
+        statements.collect::<Vec<_>>().join(" ")
+    }
+}
+
+/// Writes a whole number as the shortest yolol literal for it.
+#[cfg(feature = "std")]
+fn literal(value: impl Into<i128>) -> String
+{
+    YololNumber::from_value(value.into()).to_shortest_literal()
+}
@@ -4,9 +4,13 @@
 #![allow(clippy::zero_prefixed_literal)]
 #![allow(clippy::inconsistent_digit_grouping)]
 
+use std::convert::TryFrom;
+
 use num_traits::{
     NumCast,
-    AsPrimitive
+    AsPrimitive,
+    ToPrimitive,
+    FromPrimitive,
 };
 
 use crate::prelude::*;
@@ -17,9 +21,9 @@ fn num_helper(num: YololNumber, expected: i128)
     println!("Num: {:?}", num);
     println!("Expected value:  {}", expected);
 
-    let expected = num_traits::clamp(expected, i64::min_value().into(), i64::max_value().into());
+    let expected = num_traits::clamp(expected, i64::MIN.into(), i64::MAX.into());
 
-    assert_eq!(expected, num.get_inner(), "Expected inner: {:?}. Actual inner: {:?}", expected, num.get_inner());
+    assert_eq!(expected, num.to_raw().0, "Expected inner: {:?}. Actual inner: {:?}", expected, num.to_raw().0);
     println!();
 }
 
@@ -61,9 +65,11 @@ fn serialize_test()
 {
     use serde_json;
 
-    let num: YololNumber = NumCast::from(15.640).unwrap();
+    let num = YololNumber::try_from(15.640).unwrap();
     let out = serde_json::to_string(&num).unwrap();
     println!("serialize_test: {}", out);
+
+    assert_eq!(out, "\"15.64\"");
 }
 
 #[test]
@@ -99,6 +105,1128 @@ fn from_str_test()
     from_str_helper("-1", -1_000);
 }
 
+#[test]
+fn checked_construction_test()
+{
+    num_helper(YololNumber::try_from_value(5).unwrap(), 5_000);
+    num_helper(YololNumber::try_from_raw(RawInner(1_234)).unwrap(), 1_234);
+
+    assert_eq!(YololNumber::try_from_value(i128::MAX), Err(ConversionError::Overflow));
+    assert_eq!(YololNumber::try_from_value(i128::MIN), Err(ConversionError::Underflow));
+    assert_eq!(YololNumber::try_from_value(u128::MAX), Err(ConversionError::Overflow));
+    num_helper(YololNumber::try_from_value(2.5).unwrap(), 2_500);
+    num_helper(YololNumber::try_from_value(-0.125_f32).unwrap(), -0_125);
+    assert_eq!(YololNumber::try_from_value(2.5004), Err(ConversionError::Truncated));
+    assert_eq!(YololNumber::try_from_value(1e16), Err(ConversionError::Overflow));
+    assert_eq!(YololNumber::try_from_value(2.5).ok(), YololNumber::try_from(2.5).ok());
+    num_helper(YololNumber::try_from_value(-65.534).unwrap(), -65_534);
+    num_helper(YololNumber::try_from_value(1.1_f32).unwrap(), 1_100);
+    num_helper(YololNumber::from_value(1.005), 1_005);
+    assert_eq!(YololNumber::try_from_value(0.0005), Err(ConversionError::Truncated));
+
+    // Every value with 3 decimals is exact, even though most of them aren't exact as a float
+    for raw in -100_000..=100_000
+    {
+        let value = raw as f64 / 1000.0;
+        assert_eq!(YololNumber::try_from_value(value), Ok(YololNumber::from_raw(RawInner(raw))), "{}", value);
+        assert_eq!(YololNumber::from_value_reporting(value), (YololNumber::from_raw(RawInner(raw)), OpReport::default()), "{}", value);
+    }
+
+    assert_eq!(YololNumber::try_from_raw(RawInner(i64::MAX as i128 + 1)), Err(ConversionError::Overflow));
+    assert_eq!(YololNumber::try_from_raw(RawInner(i64::MIN as i128 - 1)), Err(ConversionError::Underflow));
+    assert_eq!(YololNumber::try_from_raw(RawInner(f64::NEG_INFINITY)), Err(ConversionError::Underflow));
+}
+
+#[test]
+fn saturating_construction_test()
+{
+    num_helper(YololNumber::saturating_from_value(i128::MAX), i128::MAX);
+    num_helper(YololNumber::saturating_from_value(i128::MIN), i128::MIN);
+    num_helper(YololNumber::saturating_from_value(u128::MAX), i128::MAX);
+    num_helper(YololNumber::saturating_from_value(2.5), 2_500);
+    num_helper(YololNumber::saturating_from_value(-2.5), -2_500);
+    num_helper(YololNumber::saturating_from_value(2.5009), 2_500);
+    num_helper(YololNumber::saturating_from_value(-2.5009), -2_500);
+
+    num_helper(YololNumber::from_raw(RawInner(i128::MAX)), i128::MAX);
+    num_helper(YololNumber::from_raw(RawInner(i128::MIN)), i128::MIN);
+    num_helper(YololNumber::from_raw(RawInner(2.5)), 0_002);
+
+    num_helper(YololNumber::from_value(i128::MAX), i128::MAX);
+}
+
+#[test]
+fn primitive_conversion_test()
+{
+    num_helper(5_i32.into(), 5_000);
+    num_helper((-5_i8).into(), -5_000);
+    num_helper(u32::MAX.into(), 4294967295_000);
+
+    num_helper(YololNumber::try_from(-7_i64).unwrap(), -7_000);
+    assert_eq!(YololNumber::try_from(i64::MAX), Err(ConversionError::Overflow));
+    assert_eq!(YololNumber::try_from(u64::MAX), Err(ConversionError::Overflow));
+
+    num_helper(YololNumber::try_from(15.640).unwrap(), 15_640);
+    assert_eq!(YololNumber::try_from(-0.0015_f64), Err(ConversionError::Truncated));
+    assert_eq!(YololNumber::try_from(0.0005), Err(ConversionError::Truncated));
+    assert_eq!(YololNumber::try_from(1.0019), Err(ConversionError::Truncated));
+    num_helper(YololNumber::try_from(-65.534).unwrap(), -65_534);
+    num_helper(YololNumber::try_from(1.1_f32).unwrap(), 1_100);
+    num_helper(YololNumber::try_from(1.5_f32).unwrap(), 1_500);
+    assert_eq!(YololNumber::try_from(f64::NAN), Err(ConversionError::NotANumber));
+    assert_eq!(YololNumber::try_from(f64::INFINITY), Err(ConversionError::Overflow));
+    assert_eq!(YololNumber::try_from(-1e300), Err(ConversionError::Underflow));
+
+    assert_eq!(i32::try_from(yolol!(-12)), Ok(-12));
+    assert_eq!(i32::try_from(YololNumber::from_raw(RawInner(1_500))), Err(ConversionError::Truncated));
+    assert_eq!(u8::try_from(yolol!(256)), Err(ConversionError::Overflow));
+    assert_eq!(u8::try_from(yolol!(-1)), Err(ConversionError::Underflow));
+    assert_eq!(f64::try_from(YololNumber::from_raw(RawInner(1_500))), Ok(1.5));
+}
+
+#[test]
+fn primitive_traits_test()
+{
+    num_helper(<YololNumber as NumCast>::from(15.640).unwrap(), 15_640);
+    num_helper(<YololNumber as NumCast>::from(-3_i64).unwrap(), -3_000);
+    num_helper(YololNumber::from_i64(12).unwrap(), 12_000);
+    assert_eq!(<YololNumber as NumCast>::from(f64::NAN), None);
+    assert_eq!(YololNumber::from_i128(i128::MAX), None);
+
+    let num = YololNumber::from_raw(RawInner(-2_750));
+    assert_eq!(num.to_i64(), Some(-2));
+    assert_eq!(num.to_u64(), None);
+    assert_eq!(num.to_f64(), Some(-2.75));
+
+    let raw: RawInner<i128> = NumCast::from(2_750_u32).unwrap();
+    num_helper(YololNumber::from_raw(raw), 2_750);
+    assert_eq!(num.to_raw().to_i64(), Some(-2_750));
+}
+
+#[test]
+fn mixed_ops_test()
+{
+    let num = yolol!(10);
+
+    num_helper(num + 5, 15_000);
+    num_helper(5 - num, -5_000);
+    num_helper(num * 2.5, 25_000);
+    num_helper(&num / 4_i64, 2_500);
+    num_helper(7 % &num, 7_000);
+    num_helper(num / 0, 0_000);
+
+    assert!(num == 10);
+    assert!(10_i64 == num);
+    assert!(num == 10.0);
+    assert!(num > 9.999);
+    assert!(-3 < num);
+    assert_eq!(num.partial_cmp(&f64::NAN), None);
+
+    // Comparisons are exact, instead of converting the primitive the way the operators do
+    let max = YololNumber::max_value();
+    let min = YololNumber::min_value();
+
+    assert!(max != i64::MAX && max < i64::MAX);
+    assert!(min != i64::MIN && min > i64::MIN);
+    assert!(max != 1e300 && max < 1e300);
+    assert!(max < f64::INFINITY && min > f64::NEG_INFINITY);
+    assert!(num != 10.0004 && num < 10.0004);
+    assert!(-num != -10.0004 && -num > -10.0004);
+    assert!(YololNumber::from_float(0.1) != 0.1);
+    assert!(YololNumber::from_raw(RawInner(0_125)) == 0.125);
+    assert!(YololNumber::from_raw(RawInner(-0_125)) == -0.125_f64);
+    assert!(YololNumber::zero() == -0.0 && YololNumber::zero() > -f64::MIN_POSITIVE);
+    assert!(max > 9_223_372_036_854_774.0 && max < 9_223_372_036_854_776.0);
+    assert!(max > 9_223_372_036_854_775_i64 && min < -9_223_372_036_854_775_i64);
+
+    for raw in -3_000..=3_000_i32
+    {
+        let num = YololNumber::from_raw(RawInner(raw));
+        let whole = raw.div_euclid(1_000);
+
+        assert_eq!(num == whole, raw % 1_000 == 0, "{:?}", num);
+        assert_eq!(num.partial_cmp(&whole), Some(raw.cmp(&(whole * 1_000))), "{:?}", num);
+    }
+
+    // A float operand is converted the same as `from_value`, truncating rather than rounding
+    assert_eq!(YololNumber::zero() + 0.0019, yolol!(0.001));
+    assert_eq!(0.0019 - YololNumber::zero(), yolol!(0.001));
+
+    for &float in &[0.0019, -0.0019, 1.005, -65.534, 2.5, 1e300, -1e300, f64::NAN]
+    {
+        for &num in &[yolol!(0), yolol!(1.5), yolol!(-3), YololNumber::MAX, YololNumber::MIN]
+        {
+            let value = YololNumber::from_value(float);
+
+            assert_eq!(num + float, num + value, "{:?} + {}", num, float);
+            assert_eq!(num - float, num - value, "{:?} - {}", num, float);
+            assert_eq!(num * float, num * value, "{:?} * {}", num, float);
+            assert_eq!(num / float, num / value, "{:?} / {}", num, float);
+            assert_eq!(num % float, num % value, "{:?} % {}", num, float);
+            assert_eq!(float - num, value - num, "{} - {:?}", float, num);
+        }
+    }
+}
+
+#[test]
+fn assign_ops_test()
+{
+    let mut num = yolol!(10);
+    let two = yolol!(2);
+
+    num += two;
+    num_helper(num, 12_000);
+    num -= &two;
+    num_helper(num, 10_000);
+    num *= two;
+    num_helper(num, 20_000);
+    num /= &two;
+    num_helper(num, 10_000);
+    num %= yolol!(3);
+    num_helper(num, 1_000);
+
+    let mut max = YololNumber::max_value();
+    max += two;
+    num_helper(max, i128::MAX);
+}
+
+#[test]
+fn checked_ops_test()
+{
+    use crate::traits::ConstRange;
+
+    let max = YololNumber::max_value();
+    let min = YololNumber::min_value();
+    let one = YololNumber::one();
+
+    // The backing i128 could hold all of these, but they're outside the Yolol range
+    assert_eq!(max.checked_add(&one), None);
+    assert_eq!(min.checked_sub(&one), None);
+    assert_eq!(max.checked_mul(&yolol!(2)), None);
+    assert_eq!(min.checked_div(&YololNumber::from_float(0.5)), None);
+    assert_eq!(max.checked_add(&-one), Some(max - one));
+    assert_eq!(yolol!(-7).checked_rem(&yolol!(3)), Some(yolol!(-1)));
+    assert_eq!(one.checked_div(&YololNumber::zero()), None);
+
+    // The same goes for any other range
+    type Small = crate::number::YololNumber<i64, ConstRange<-10_000, 10_000>>;
+    let ten = Small::from_value(10);
+
+    assert_eq!(ten.checked_add(&Small::one()), None);
+    assert_eq!((-ten).checked_sub(&Small::one()), None);
+    assert_eq!(ten.checked_mul(&Small::from_value(-1)), Some(-ten));
+    assert_eq!(ten.checked_div(&Small::from_float(0.5)), None);
+}
+
+#[test]
+fn iter_traits_test()
+{
+    let nums: Vec<YololNumber> = (1..=5).map(YololNumber::from_value).collect();
+
+    num_helper(nums.iter().sum(), 15_000);
+    num_helper(nums.iter().copied().product(), 120_000);
+
+    // Saturating at each step means we can't come back down after hitting the max
+    let saturating = vec![YololNumber::max_value(), yolol!(1), yolol!(-1)];
+    num_helper(saturating.into_iter().sum(), i64::MAX as i128 - 1_000);
+
+    num_helper(YololNumber::default(), 0_000);
+
+    let mut vars = std::collections::HashMap::new();
+    vars.insert(yolol!(1), yolol!(2));
+    assert_eq!(vars.get(&YololNumber::from_raw(RawInner(1_000))), Some(&yolol!(2)));
+}
+
+#[test]
+fn num_traits_test()
+{
+    let max = YololNumber::max_value();
+    let min = YololNumber::min_value();
+    let one = YololNumber::one();
+    let half = YololNumber::from_raw(RawInner(0_500));
+
+    num_helper(max.saturating_add(one), i128::MAX);
+    num_helper(min.saturating_sub(one), i128::MIN);
+
+    num_helper(max.wrapping_add(&YololNumber::from_raw(RawInner(1))), i64::MIN.into());
+    num_helper(min.wrapping_sub(&YololNumber::from_raw(RawInner(1))), i64::MAX.into());
+    num_helper(one.wrapping_add(&half), 1_500);
+    num_helper(max.wrapping_mul(&yolol!(2)), -0_002);
+
+    assert_eq!(min.checked_neg(), None);
+    num_helper(half.checked_neg().unwrap(), -0_500);
+
+    num_helper(Pow::pow(yolol!(2), yolol!(3)), 8_000);
+    num_helper(yolol!(4).inv(), 0_250);
+    num_helper(YololNumber::zero().inv(), 0_000);
+    num_helper(yolol!(3).mul_add(half, one), 2_500);
+
+    let seven = yolol!(-7);
+    let two = YololNumber::from_raw(RawInner(2_000));
+    num_helper(Euclid::div_euclid(&seven, &two), -4_000);
+    num_helper(Euclid::rem_euclid(&seven, &two), 1_000);
+    num_helper(Euclid::div_euclid(&seven, &-two), 4_000);
+    num_helper(Euclid::rem_euclid(&seven, &-two), 1_000);
+    num_helper(Euclid::rem_euclid(&YololNumber::from_raw(RawInner(5_500)), &two), 1_500);
+    assert_eq!(seven.checked_div_euclid(&YololNumber::zero()), None);
+    assert_eq!(max.checked_div_euclid(&half), None);
+
+    num_helper(YololNumber::from_f64(-1.2345).unwrap(), -1_235);
+}
+
+#[test]
+fn radix_test()
+{
+    num_helper(YololNumber::from_str_radix("-1f.8", 16).unwrap(), -31_500);
+    num_helper(YololNumber::from_str_radix("+101.11", 2).unwrap(), 5_750);
+    num_helper(YololNumber::from_str_radix("Z", 36).unwrap(), 35_000);
+    num_helper(YololNumber::from_str_radix("0.0001", 10).unwrap(), 0_000);
+    num_helper(YololNumber::from_str_radix("0.1", 3).unwrap(), 0_333);
+
+    assert_eq!(YololNumber::from_str_radix("1", 1), Err(FromStrRadixError::InvalidRadix(1)));
+    assert_eq!(YololNumber::from_str_radix("1", 37), Err(FromStrRadixError::InvalidRadix(37)));
+    assert_eq!(YololNumber::from_str_radix(".5", 10), Err(FromStrRadixError::NoMainDigits));
+    assert_eq!(YololNumber::from_str_radix("12", 2), Err(FromStrRadixError::InvalidDigit('2')));
+    assert_eq!(YololNumber::from_str_radix("1.1.1", 2), Err(FromStrRadixError::InvalidDigit('.')));
+    assert_eq!(YololNumber::from_str_radix("fffffffffffffff", 16), Ok(YololNumber::max_value()));
+    assert_eq!(YololNumber::from_str_radix("-fffffffffffffff", 16), Ok(YololNumber::min_value()));
+    assert_eq!(YololNumber::from_str_radix("1ffffffffffffffffffffffffffffffff", 16), Ok(YololNumber::max_value()));
+    assert_eq!(YololNumber::from_str_radix("1ffffffffffffffffffffffffffffffffg", 16), Err(FromStrRadixError::InvalidDigit('g')));
+
+    // Base 10 parses exactly the same as `FromStr`, including saturating
+    for &input in &["0", "-1.5", "12.3456", "9223372036854775.807", "9223372036854775.808", "-9223372036854775.809", "-99999999999999999999"]
+    {
+        assert_eq!(YololNumber::from_str_radix(input, 10).ok(), input.parse::<YololNumber>().ok(), "{:?}", input);
+    }
+
+    // Including main digits too long for the backing type
+    let huge = "99999999999999999999999999999999999999999";
+    assert_eq!(huge.parse::<YololNumber>(), Ok(YololNumber::max_value()));
+    assert_eq!(YololNumber::from_str_radix(huge, 10), Ok(YololNumber::max_value()));
+
+    assert_eq!(YololNumber::from_raw(RawInner(-31_500)).to_string_radix(16), "-1f.8");
+    assert_eq!(YololNumber::from_raw(RawInner(0_001)).to_string_radix(10), "0.001");
+    assert_eq!(YololNumber::from_raw(RawInner(-0_100)).to_string_radix(2), "-0.0001100111");
+    assert_eq!(YololNumber::max_value().to_string_radix(10), YololNumber::max_value().to_string());
+
+    for radix in 2..=36
+    {
+        let inners = (-5_000..5_000).chain(vec![i64::MIN as i128, i64::MAX as i128]);
+
+        for inner in inners
+        {
+            let num = YololNumber::from_raw(RawInner(inner));
+            let string = num.to_string_radix(radix);
+            assert_eq!(YololNumber::from_str_radix(&string, radix), Ok(num), "Radix {} string {:?}", radix, string);
+        }
+    }
+}
+
+#[test]
+fn i64_backing_test()
+{
+    type Num64 = crate::number::YololNumber<i64>;
+
+    let max = Num64::max_value();
+    let min = Num64::min_value();
+    let half = Num64::from_raw(RawInner(0_500));
+    let three_halves = Num64::from_raw(RawInner(1_500));
+
+    assert_eq!(std::mem::size_of::<Num64>(), 8);
+
+    assert_eq!(max.to_string(), "9223372036854775.807");
+    assert_eq!(min.to_string(), "-9223372036854775.808");
+    assert_eq!("-9223372036854775.808".parse::<Num64>(), Ok(min));
+    assert_eq!(Num64::from_str_radix("-9223372036854775.808", 10), Ok(min));
+    assert_eq!("99999999999999999999".parse::<Num64>(), Ok(max));
+    assert_eq!("-99999999999999999999".parse::<Num64>(), Ok(min));
+    assert_eq!(max.ceiling(), max);
+
+    assert_eq!(max * three_halves, max);
+    assert_eq!(min * three_halves, min);
+    assert_eq!(max * half, Num64::from_raw(RawInner(4611686018427387_903_i64)));
+    assert_eq!(max / half, max);
+    assert_eq!(min / half, min);
+    assert_eq!(max / three_halves, Num64::from_raw(RawInner(6148914691236517_204_i64)));
+    assert_eq!(max % three_halves, Num64::from_raw(RawInner(0_307)));
+    assert_eq!(-min, max);
+    assert_eq!(min.abs(), max);
+
+    // Every result should be identical to what an i128 backed number gives
+    let values = [i64::MIN, i64::MIN + 1, -1_000_000_000_000, -1_500, -1, 0, 1, 999, 1_500, 123_456_789, i64::MAX];
+    for &left in values.iter()
+    {
+        let (left64, left128) = (Num64::from_raw(RawInner(left)), YololNumber::from_raw(RawInner(left)));
+        assert_eq!(left64.ceiling().to_string(), left128.ceiling().to_string());
+        assert_eq!(left64.floor().to_string(), left128.floor().to_string());
+        assert_eq!(left64.to_string().parse::<Num64>(), Ok(left64));
+
+        for &right in values.iter()
+        {
+            let (left64, right64) = (Num64::from_raw(RawInner(left)), Num64::from_raw(RawInner(right)));
+            let (left128, right128) = (YololNumber::from_raw(RawInner(left)), YololNumber::from_raw(RawInner(right)));
+
+            assert_eq!((left64 + right64).to_string(), (left128 + right128).to_string());
+            assert_eq!((left64 - right64).to_string(), (left128 - right128).to_string());
+            assert_eq!((left64 * right64).to_string(), (left128 * right128).to_string(), "{} * {}", left, right);
+            assert_eq!((left64 / right64).to_string(), (left128 / right128).to_string(), "{} / {}", left, right);
+            assert_eq!((left64 % right64).to_string(), (left128 % right128).to_string(), "{} % {}", left, right);
+        }
+    }
+}
+
+#[test]
+fn custom_range_test()
+{
+    use crate::traits::{YololRange, ConstRange};
+
+    #[derive(Debug, Clone, Copy)]
+    struct Small;
+
+    impl YololRange for Small
+    {
+        const MIN_INNER: i128 = -10_000;
+        const MAX_INNER: i128 = 10_000;
+    }
+
+    type SmallNum = crate::number::YololNumber<i128, Small>;
+    type TinyNum = crate::number::YololNumber<i32, ConstRange<-2_500, 1_500>>;
+
+    assert_eq!(SmallNum::max_value().to_string(), "10");
+    assert_eq!(SmallNum::min_value().to_string(), "-10");
+    assert_eq!((SmallNum::from_value(6) + SmallNum::from_value(6)).to_string(), "10");
+    assert_eq!((SmallNum::from_value(-4) * SmallNum::from_value(3)).to_string(), "-10");
+    assert_eq!((SmallNum::from_value(1) / SmallNum::from_raw(RawInner(0_050))).to_string(), "10");
+    assert_eq!(SmallNum::from_value(50).to_string(), "10");
+    assert_eq!(SmallNum::try_from_value(11), Err(ConversionError::Overflow));
+    assert_eq!("-12.5".parse::<SmallNum>().unwrap().to_string(), "-10");
+
+    assert_eq!((TinyNum::from_value(1) + TinyNum::from_value(1)).to_string(), "1.5");
+    assert_eq!((-TinyNum::min_value()).to_string(), "1.5");
+    assert_eq!(TinyNum::from_value(-3).to_string(), "-2.5");
+    assert_eq!(std::mem::size_of::<TinyNum>(), 4);
+
+    // About as wide as a range backed by an i128 can be while the product of any two values still fits
+    type WideNum = crate::number::YololNumber<i128, ConstRange<-13_000_000_000_000_000_000, 13_000_000_000_000_000_000>>;
+
+    let max = WideNum::max_value();
+    let min = WideNum::min_value();
+
+    assert_eq!(max * max, max);
+    assert_eq!(max * min, min);
+    assert_eq!(max / WideNum::from_raw(RawInner(1)), max);
+    assert_eq!(max * WideNum::from_raw(RawInner(0_500)), WideNum::from_raw(RawInner(6_500_000_000_000_000_000_i128)));
+    assert_eq!(max.wrapping_add(&WideNum::from_raw(RawInner(1))), min);
+    assert_eq!(min.wrapping_sub(&WideNum::from_raw(RawInner(1))), max);
+    assert_eq!(max.wrapping_mul(&max), WideNum::from_raw(RawInner(-6_500_000_000_000_000_i128)));
+    assert_eq!(max.wrapping_mul(&min), WideNum::from_raw(RawInner(6_500_000_000_000_000_i128)));
+}
+
+#[test]
+fn modulo_test()
+{
+    let check = |left: YololNumber, right: YololNumber, expected: &str| {
+        assert_eq!((left % right).to_string(), expected, "{} % {}", left, right);
+    };
+
+    check(yolol!(7), yolol!(3), "1");
+    check(yolol!(-7), yolol!(3), "-1");
+    check(yolol!(7), yolol!(-3), "1");
+    check(yolol!(-7), yolol!(-3), "-1");
+    check(yolol!(5.5), yolol!(2), "1.5");
+    check(yolol!(-5.5), yolol!(2), "-1.5");
+    check(yolol!(1), yolol!(0.3), "0.1");
+    check(yolol!(0.007), yolol!(0.002), "0.001");
+    check(yolol!(7), yolol!(0), "0");
+    check(yolol!(-7), yolol!(0), "0");
+
+    assert_eq!(YololNumber::min_value() % yolol!(-0.001), YololNumber::zero());
+    assert_eq!(i128::MIN.yolol_mod(-1), 0);
+    assert_eq!(i32::MIN.yolol_mod(-1), 0);
+
+    type SmallNum = crate::number::YololNumber<i64>;
+    assert_eq!(SmallNum::min_value() % SmallNum::from_raw(RawInner(-1)), SmallNum::zero());
+
+    assert_eq!(yolol!(-7).rem_euclid(yolol!(3)), yolol!(2));
+    assert_eq!(yolol!(-7).rem_euclid(yolol!(-3)), yolol!(2));
+    assert_eq!(yolol!(-5.5).rem_euclid(yolol!(2)), yolol!(0.5));
+    assert_eq!(yolol!(-7).div_euclid(yolol!(3)), yolol!(-3));
+    assert_eq!(yolol!(-7).div_euclid(yolol!(-3)), yolol!(3));
+    assert_eq!(yolol!(7).div_euclid(yolol!(0)), yolol!(0));
+    assert_eq!(yolol!(7).rem_euclid(yolol!(0)), yolol!(0));
+    assert_eq!(YololNumber::max_value().div_euclid(yolol!(0.5)), YololNumber::max_value());
+
+    // The quotient at the bottom of the range is past the top of an i64, and saturates the same as an i128
+    let tiny = SmallNum::from_raw(RawInner(-1));
+    assert_eq!(SmallNum::min_value().div_euclid(tiny), SmallNum::max_value());
+    assert_eq!(YololNumber::min_value().div_euclid(yolol!(-0.001)), YololNumber::max_value());
+    assert_eq!(SmallNum::min_value().rem_euclid(tiny), SmallNum::zero());
+    assert_eq!(SmallNum::min_value().div_euclid(SmallNum::from_value(-2)), SmallNum::from_value(4611686018427388_i64));
+    assert_eq!(SmallNum::min_value().checked_div_euclid(&tiny), None);
+}
+
+#[test]
+fn fused_ops_test()
+{
+    let max = YololNumber::MAX;
+    let min = YololNumber::MIN;
+
+    assert_eq!(yolol!(0.001) * yolol!(0.5) / yolol!(0.5), yolol!(0));
+    assert_eq!(yolol!(0.001).mul_div(yolol!(0.5), yolol!(0.5)), yolol!(0.001));
+    assert_eq!(max * yolol!(2) / yolol!(4), max / yolol!(4));
+    assert_eq!(max.mul_div(yolol!(2), yolol!(4)), max / yolol!(2));
+    assert_eq!(yolol!(10).mul_div(yolol!(3), yolol!(7)), yolol!(4.285));
+    assert_eq!(yolol!(-10).mul_div(yolol!(3), yolol!(7)), yolol!(-4.285));
+    assert_eq!(max.mul_div(yolol!(-2), yolol!(0.5)), min);
+    assert_eq!(min.mul_div(yolol!(-2), yolol!(-0.5)), min);
+    assert_eq!(min.mul_div(min, yolol!(0.001)), max);
+    assert_eq!(yolol!(7).mul_div(yolol!(2), yolol!(0)), yolol!(0));
+
+    assert_eq!(yolol!(-0.5) * yolol!(0.001) + yolol!(0.001), yolol!(0.001));
+    assert_eq!(yolol!(-0.5).fused_mul_add(yolol!(0.001), yolol!(0.001)), yolol!(0));
+    assert_eq!(max * yolol!(2) + min, yolol!(-0.001));
+    assert_eq!(max.fused_mul_add(yolol!(2), min), max - yolol!(0.001));
+    assert_eq!(yolol!(1.5).fused_mul_add(yolol!(1.5), yolol!(-1)), yolol!(1.25));
+    assert_eq!(min.fused_mul_add(min, min), max);
+    assert_eq!(min.fused_mul_add(yolol!(2), max), min);
+
+    // The trait is the chained version, since generic code should get the same results as yolol
+    assert_eq!(MulAdd::mul_add(yolol!(-0.5), yolol!(0.001), yolol!(0.001)), yolol!(0.001));
+    assert_eq!(MulAdd::mul_add(max, yolol!(2), min), yolol!(-0.001));
+
+    type SmallNum = crate::number::YololNumber<i32>;
+    assert_eq!(SmallNum::MAX.mul_div(SmallNum::from_inner_const(3_000), SmallNum::from_inner_const(4_000)).to_string(), "1610612.735");
+}
+
+#[test]
+fn reporting_test()
+{
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use crate::number::report::{self, OpKind};
+
+    let max = YololNumber::max_value();
+    let min = YololNumber::min_value();
+
+    let clean = OpReport::default();
+    let high = OpReport { saturated_high: true, ..OpReport::default() };
+    let low = OpReport { saturated_low: true, ..OpReport::default() };
+    let truncated = OpReport { truncated: true, ..OpReport::default() };
+    let nan = OpReport { nan_mapped: true, ..OpReport::default() };
+    let div_zero = OpReport { div_by_zero_mapped: true, ..OpReport::default() };
+
+    assert_eq!(yolol!(1).add_reporting(yolol!(2)), (yolol!(3), clean));
+    assert_eq!(max.add_reporting(yolol!(0.001)), (max, high));
+    assert_eq!(min.add_reporting(yolol!(-0.001)), (min, low));
+    assert_eq!(min.sub_reporting(yolol!(0.001)), (min, low));
+    assert_eq!(yolol!(0.5).mul_reporting(yolol!(0.5)), (yolol!(0.25), clean));
+    assert_eq!(yolol!(0.5).mul_reporting(yolol!(0.001)), (yolol!(0), truncated));
+    assert_eq!(max.mul_reporting(yolol!(-2)), (min, low));
+    assert_eq!(yolol!(1).div_reporting(yolol!(4)), (yolol!(0.25), clean));
+    assert_eq!(yolol!(1).div_reporting(yolol!(3)), (yolol!(0.333), truncated));
+    assert_eq!(yolol!(1).div_reporting(yolol!(0)), (yolol!(0), div_zero));
+    assert_eq!(max.div_reporting(yolol!(0.5)), (max, high));
+    assert_eq!(yolol!(-7).rem_reporting(yolol!(3)), (yolol!(-1), clean));
+    assert_eq!(yolol!(7).rem_reporting(yolol!(0)), (yolol!(0), div_zero));
+
+    assert_eq!(YololNumber::from_value_reporting(5), (yolol!(5), clean));
+    assert_eq!(YololNumber::from_value_reporting(1.5_f64), (yolol!(1.5), clean));
+    assert_eq!(YololNumber::from_value_reporting(1.5005_f64), (yolol!(1.5), truncated));
+    assert_eq!(YololNumber::from_value_reporting(i128::MAX), (max, high));
+    assert_eq!(YololNumber::from_value_reporting(f64::NAN), (min, nan));
+    assert_eq!(YololNumber::from_raw_reporting(RawInner(i128::MIN)), (min, low));
+    assert_eq!(YololNumber::from_float_reporting(0.25), (yolol!(0.25), clean));
+    assert_eq!(YololNumber::from_float_reporting(0.0004), (yolol!(0), truncated));
+    assert_eq!(YololNumber::from_float_reporting(1e300), (max, high));
+    assert_eq!(YololNumber::from_float_reporting(f64::NAN), (min, nan));
+
+    assert_eq!(YololNumber::parse_reporting("1.250"), Ok((yolol!(1.25), clean)));
+    assert_eq!(YololNumber::parse_reporting("1.2509"), Ok((yolol!(1.25), truncated)));
+    assert_eq!(YololNumber::parse_reporting("-0.0001"), Ok((yolol!(0), truncated)));
+    assert_eq!(YololNumber::parse_reporting("99999999999999999999"), Ok((max, high)));
+    assert!(YololNumber::parse_reporting("nope").is_err());
+
+    assert!(clean.is_clean());
+    assert!((high | truncated).saturated());
+    assert_eq!(high | truncated, OpReport { saturated_high: true, truncated: true, ..OpReport::default() });
+
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&events);
+    report::set_observer(move |kind, report| sink.borrow_mut().push((kind, report)));
+
+    let _ = yolol!(1) + yolol!(2);
+    let _ = max + yolol!(1);
+    let _ = yolol!(1) / yolol!(0);
+    let _: YololNumber = [yolol!(0.5), yolol!(0.001)].iter().product();
+    let _ = 5_i128.yolol_mod(0);
+    let _ = i32::MIN.yolol_sub(1);
+    let _ = YololNumber::from_str_radix("0.1", 3);
+    let _ = YololNumber::from_str_radix("1.0001", 10);
+    let _ = YololNumber::from_str_radix("0.8", 16);
+    let _ = YololNumber::from_str_radix("1.0000", 10);
+    let _ = YololNumber::from_str_radix("1ffffffffffffffffffffffffffffffff.1", 16);
+
+    report::clear_observer();
+    let _ = max + yolol!(1);
+
+    assert_eq!(*events.borrow(), vec![
+        (OpKind::Add, high),
+        (OpKind::Div, div_zero),
+        (OpKind::Mul, truncated),
+        (OpKind::Mod, div_zero),
+        (OpKind::Sub, low),
+        (OpKind::Parse, truncated),
+        (OpKind::Parse, truncated),
+        (OpKind::Parse, high | truncated),
+    ]);
+}
+
+#[test]
+fn shadowed_test()
+{
+    let exact = Shadowed::new(yolol!(1.5)) + Shadowed::new(yolol!(2.25)) * Shadowed::new(yolol!(2));
+    assert_eq!(exact.value(), YololNumber::from_raw(RawInner(6_000)));
+    assert_eq!(exact.ideal(), 6.0);
+    assert_eq!(exact.max_abs_error(), 0.0);
+    assert_eq!(exact.max_rel_error(), 0.0);
+
+    let third = Shadowed::new(yolol!(1)) / Shadowed::new(yolol!(3));
+    assert_eq!(third.value().to_string(), "0.333");
+    assert!((third.abs_error() - 0.000_333_333).abs() < 1e-9);
+    assert!((third.rel_error() - 0.001).abs() < 1e-9);
+
+    // Multiplying back up makes the error bigger, and it's carried past steps that are exact
+    let scaled = third * Shadowed::new(yolol!(3000)) - Shadowed::new(yolol!(1000));
+    assert_eq!(scaled.value().to_string(), "-1");
+    assert!(scaled.ideal().abs() < 1e-9);
+    assert!((scaled.max_abs_error() - 1.0).abs() < 1e-9);
+    assert_eq!(scaled.max_rel_error(), f64::INFINITY);
+
+    let lost = Shadowed::new(yolol!(0.001)) * Shadowed::new(yolol!(0.5)) * Shadowed::new(yolol!(1000));
+    assert_eq!(lost.value().to_string(), "0");
+    assert!((lost.ideal() - 0.5).abs() < 1e-9);
+    assert!((lost.max_rel_error() - 1.0).abs() < 1e-9);
+
+    let saturated = Shadowed::new(YololNumber::max_value()) * Shadowed::new(yolol!(2));
+    assert_eq!(saturated.value(), YololNumber::max_value());
+    assert!((saturated.rel_error() - 0.5).abs() < 1e-9);
+
+    let divided = Shadowed::new(yolol!(1)) / Shadowed::new(yolol!(0));
+    assert_eq!(divided.value().to_string(), "0");
+    assert_eq!(divided.max_abs_error(), f64::INFINITY);
+    assert_eq!(divided.max_rel_error(), f64::INFINITY);
+    assert_eq!((Shadowed::new(yolol!(0)) / Shadowed::new(yolol!(0))).max_abs_error(), f64::INFINITY);
+
+    let converted = Shadowed::from_float(0.1234);
+    assert_eq!(converted.value().to_string(), "0.123");
+    assert!((converted.abs_error() - 0.0004).abs() < 1e-9);
+
+    assert_eq!((-Shadowed::new(yolol!(2))).value().to_string(), "-2");
+    assert_eq!(Shadowed::new(yolol!(30)).sin().value().to_string(), "0.5");
+    assert_eq!((Shadowed::new(yolol!(-7)) % Shadowed::new(yolol!(3))).value().to_string(), "-1");
+    assert_eq!(third.to_string(), "0.333 (ideal 0.3333333333333333)");
+}
+
+#[test]
+fn interval_test()
+{
+    use crate::traits::ConstRange;
+
+    type SmallNum = crate::number::YololNumber<i32, ConstRange<-3_000, 2_000>>;
+    type SmallInterval = crate::number::interval::YololInterval<i32, ConstRange<-3_000, 2_000>>;
+
+    let small = |lo: i32, hi: i32| SmallInterval::new(SmallNum::from_raw(RawInner(lo)), SmallNum::from_raw(RawInner(hi)));
+    let every = |interval: SmallInterval| (interval.lo().to_raw().0..=interval.hi().to_raw().0).map(|raw| SmallNum::from_raw(RawInner(raw)));
+
+    let intervals = [
+        small(-3_000, -2_950), small(-1_020, -980), small(-40, 25), small(0, 0), small(-1, 1),
+        small(1, 60), small(480, 520), small(990, 1_050), small(1_960, 2_000),
+    ];
+
+    type BinaryOps = [(&'static str, fn(SmallNum, SmallNum) -> SmallNum, fn(SmallInterval, SmallInterval) -> SmallInterval); 5];
+    let ops: BinaryOps = [
+        ("+", |a, b| a + b, |a, b| a + b),
+        ("-", |a, b| a - b, |a, b| a - b),
+        ("*", |a, b| a * b, |a, b| a * b),
+        ("/", |a, b| a / b, |a, b| a / b),
+        ("%", |a, b| a % b, |a, b| a % b),
+    ];
+
+    for &left in &intervals
+    {
+        for &right in &intervals
+        {
+            for &(name, num_op, interval_op) in &ops
+            {
+                let result = interval_op(left, right);
+                let actual: Vec<_> = every(left).flat_map(|a| every(right).map(move |b| num_op(a, b))).collect();
+
+                for num in &actual
+                {
+                    assert!(result.contains(*num), "{:?} {} {:?} gave {:?}, missing {}", left, name, right, result, num);
+                }
+
+                // Apart from modulo, the integer operators give the tightest interval possible
+                if name != "%"
+                {
+                    assert_eq!(result.lo(), *actual.iter().min().unwrap(), "{:?} {} {:?}", left, name, right);
+                    assert_eq!(result.hi(), *actual.iter().max().unwrap(), "{:?} {} {:?}", left, name, right);
+                }
+            }
+
+            assert!(every(left).all(|a| (-left).contains(-a) && (!left).contains(!a)));
+        }
+    }
+
+    let interval = |lo, hi| YololInterval::new(lo, hi);
+    let every = |interval: YololInterval| {
+        let (lo, hi) = (interval.lo().to_raw().0, interval.hi().to_raw().0);
+        (lo..=hi).map(|raw| YololNumber::from_raw(RawInner(raw)))
+    };
+
+    type UnaryOps = [(&'static str, fn(YololNumber) -> YololNumber, fn(YololInterval) -> YololInterval); 7];
+    let ops: UnaryOps = [
+        ("sin", YololNumber::sin, YololInterval::sin),
+        ("cos", YololNumber::cos, YololInterval::cos),
+        ("tan", YololNumber::tan, YololInterval::tan),
+        ("asin", YololNumber::asin, YololInterval::asin),
+        ("acos", YololNumber::acos, YololInterval::acos),
+        ("atan", YololNumber::atan, YololInterval::atan),
+        ("sqrt", YololNumber::sqrt, YololInterval::sqrt),
+    ];
+
+    let inputs = [
+        interval(yolol!(-1.5), yolol!(-0.5)), interval(yolol!(-0.2), yolol!(0.3)), interval(yolol!(0.9), yolol!(1.1)), interval(yolol!(80), yolol!(100)),
+        interval(yolol!(170), yolol!(190)), interval(yolol!(-95), yolol!(-85)), interval(yolol!(260), yolol!(275)), interval(yolol!(359), yolol!(362)),
+    ];
+
+    for &input in &inputs
+    {
+        for &(name, num_op, interval_op) in &ops
+        {
+            let result = interval_op(input);
+            for a in every(input)
+            {
+                assert!(result.contains(num_op(a)), "{}({:?}) gave {:?}, missing {}", name, input, result, num_op(a));
+            }
+        }
+    }
+
+    assert_eq!(interval(yolol!(0), yolol!(400)).sin(), interval(yolol!(-1), yolol!(1)));
+    assert_eq!(interval(yolol!(80), yolol!(100)).tan(), YololInterval::full());
+    assert_eq!(interval(yolol!(10), yolol!(20)).tan(), YololInterval::new(yolol!(10).tan(), yolol!(20).tan()));
+    assert_eq!(interval(yolol!(4), yolol!(9)).sqrt(), interval(yolol!(2), yolol!(3)));
+    assert_eq!(interval(yolol!(-4), yolol!(9)).sqrt(), YololInterval::new(YololNumber::min_value(), yolol!(3)));
+
+    let bases = interval(yolol!(0), yolol!(2));
+    let powers = interval(yolol!(-1), yolol!(2));
+    let result = bases.pow(powers);
+    for a in every(bases).step_by(7)
+    {
+        for b in every(powers).step_by(13)
+        {
+            assert!(result.contains(a.pow(b)), "{} ^ {} missing from {:?}", a, b, result);
+        }
+    }
+    assert_eq!(interval(yolol!(-1), yolol!(2)).pow(powers), YololInterval::full());
+
+    let input = interval(yolol!(1), yolol!(10));
+    let formula = (input * interval(yolol!(2), yolol!(2)) + interval(yolol!(3), yolol!(3))) / (input - interval(yolol!(0.5), yolol!(0.5)));
+    assert!(!formula.touches_bounds());
+    assert!(!(input - interval(yolol!(0.5), yolol!(0.5))).contains_zero());
+    assert!((YololInterval::point(YololNumber::max_value()) + input).touches_bounds());
+    assert_eq!(interval(yolol!(-4), yolol!(-2)).hull(interval(yolol!(1), yolol!(3))), interval(yolol!(-4), yolol!(3)));
+}
+
+#[test]
+fn format_into_test()
+{
+    use crate::traits::ConstRange;
+
+    type FullI32 = crate::number::YololNumber<i32, ConstRange<{ i32::MIN as i128 }, { i32::MAX as i128 }>>;
+
+    let mut buf = [0_u8; YololNumber::MAX_FORMATTED_LEN];
+
+    for &input in &["0", "1", "-1", "0.001", "-0.001", "0.1", "-0.12", "12.345", "-1000.5", "9223372036854775.807", "-9223372036854775.808"]
+    {
+        let num = input.parse::<YololNumber>().unwrap();
+        assert_eq!(num.format_into(&mut buf), input);
+        assert_eq!(num.format_into(&mut buf), num.to_string());
+    }
+
+    // The buffer only has to fit the number being formatted
+    let mut small = [0_u8; 4];
+    assert_eq!(yolol!(-12).format_into(&mut small), "-12");
+
+    let mut buf = [0_u8; FullI32::MAX_FORMATTED_LEN];
+    assert_eq!(FullI32::min_value().format_into(&mut buf), "-2147483.648");
+    assert_eq!(FullI32::MAX_FORMATTED_LEN, "-2147483.648".len());
+    assert_eq!(FullI32::max_value().to_string(), "2147483.647");
+
+    let json = serde_json::to_string(&yolol!(-0.05)).unwrap();
+    assert_eq!(json, "\"-0.05\"");
+}
+
+#[test]
+fn consts_test()
+{
+    use crate::traits::ConstRange;
+
+    type SmallNum = crate::number::YololNumber<i32>;
+    type TinyNum = crate::number::YololNumber<i64, ConstRange<-500, 1_500>>;
+
+    const HALF: YololNumber = yolol!(0.5);
+    const SCALED: YololNumber = YololNumber::from_str_const("-12.340");
+
+    assert_eq!(HALF, "0.5".parse::<YololNumber>().unwrap());
+    assert_eq!(SCALED.to_string(), "-12.34");
+    assert_eq!(yolol!("-0.5"), -HALF);
+    assert_eq!(yolol!(-0.5), -HALF);
+    assert_eq!(yolol!("+3"), YololNumber::from_value(3));
+    assert_eq!(yolol!(1_000.250), "1000.25".parse::<YololNumber>().unwrap());
+    assert_eq!(yolol!("7."), YololNumber::from_value(7));
+    assert_eq!(yolol!(0.0010), YololNumber::from_raw(RawInner(1)));
+    assert_eq!(yolol!(9223372036854775.807), YololNumber::max_value());
+    assert_eq!(yolol!(-9223372036854775.808), YololNumber::min_value());
+
+    for &input in &["0", "-0", "+1", "123.456", "-0.001", "00042.100", "5.0000"]
+    {
+        assert_eq!(YololNumber::from_str_const(input), input.parse::<YololNumber>().unwrap(), "{:?}", input);
+        assert_eq!(SmallNum::from_str_const(input), input.parse::<SmallNum>().unwrap(), "{:?}", input);
+    }
+
+    assert_eq!(YololNumber::ZERO, YololNumber::zero());
+    assert_eq!(YololNumber::ONE, YololNumber::one());
+    assert_eq!(YololNumber::MAX, YololNumber::max_value());
+    assert_eq!(YololNumber::MIN, YololNumber::min_value());
+    assert_eq!(YololNumber::PI, YololNumber::from_float(core::f64::consts::PI));
+    assert_eq!(YololNumber::E, YololNumber::from_float(core::f64::consts::E));
+
+    assert_eq!(SmallNum::MAX, SmallNum::max_value());
+    assert_eq!(SmallNum::MIN, SmallNum::min_value());
+    assert_eq!(TinyNum::ONE, TinyNum::one());
+    assert_eq!(TinyNum::MIN, TinyNum::min_value());
+    assert_eq!(TinyNum::PI.to_string(), "1.5");
+    assert_eq!(TinyNum::from_inner_const(-2_000), TinyNum::min_value());
+
+    for &input in &["", "-", "1.2.3", ".5", "1.0001", "1e3", "1_000", "9223372036854775.808", "1 "]
+    {
+        assert!(std::panic::catch_unwind(|| YololNumber::from_str_const(input)).is_err(), "{:?}", input);
+    }
+}
+
+#[test]
+fn atomic_test()
+{
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::Arc;
+    use std::sync::atomic::Ordering;
+    use crate::number::report::{self, OpKind};
+
+    let atomic = AtomicYololNumber::new(yolol!(1.5));
+
+    assert_eq!(atomic.load(Ordering::SeqCst), yolol!(1.5));
+    atomic.store(yolol!(-2), Ordering::SeqCst);
+    assert_eq!(atomic.swap(yolol!(3), Ordering::SeqCst), yolol!(-2));
+    assert_eq!(atomic.compare_exchange(yolol!(1), yolol!(4), Ordering::SeqCst, Ordering::SeqCst), Err(yolol!(3)));
+    assert_eq!(atomic.compare_exchange(yolol!(3), yolol!(4), Ordering::SeqCst, Ordering::SeqCst), Ok(yolol!(3)));
+    assert_eq!(atomic.fetch_add(yolol!(0.25), Ordering::SeqCst), yolol!(4));
+    assert_eq!(atomic.fetch_sub(yolol!(10), Ordering::AcqRel), yolol!(4.25));
+    assert_eq!(format!("{:?}", atomic), format!("{:?}", yolol!(-5.75)));
+    assert_eq!(atomic.into_inner(), yolol!(-5.75));
+
+    // Saturating the same as the operators, and only being reported once
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&seen);
+    report::set_observer(move |kind, op_report| sink.borrow_mut().push((kind, op_report)));
+
+    let atomic = AtomicYololNumber::new(YololNumber::MAX);
+    assert_eq!(atomic.fetch_add(yolol!(1), Ordering::Relaxed), YololNumber::MAX);
+    assert_eq!(atomic.load(Ordering::Relaxed), YololNumber::MAX);
+    atomic.store(YololNumber::MIN, Ordering::Release);
+    atomic.fetch_sub(yolol!(1), Ordering::Release);
+    assert_eq!(atomic.load(Ordering::Acquire), YololNumber::MIN);
+
+    report::clear_observer();
+    assert_eq!(*seen.borrow(), vec![
+        (OpKind::Add, OpReport { saturated_high: true, ..OpReport::default() }),
+        (OpKind::Sub, OpReport { saturated_low: true, ..OpReport::default() }),
+    ]);
+
+    let shared = Arc::new(AtomicYololNumber::default());
+    let threads: Vec<_> = (0..4).map(|_| {
+        let shared = Arc::clone(&shared);
+        std::thread::spawn(move || {
+            for _ in 0..1000
+            {
+                shared.fetch_add(yolol!(0.001), Ordering::Relaxed);
+                shared.fetch_add(yolol!(1), Ordering::Relaxed);
+                shared.fetch_sub(yolol!(0.5), Ordering::Relaxed);
+            }
+        })
+    }).collect();
+
+    for thread in threads
+    {
+        thread.join().unwrap();
+    }
+
+    assert_eq!(shared.load(Ordering::SeqCst), yolol!(2004));
+
+    type SmallAtomic = crate::number::atomic::AtomicYololNumber<i32>;
+    let small = SmallAtomic::from(crate::number::YololNumber::<i32>::MIN);
+    assert_eq!(small.fetch_sub(crate::number::YololNumber::<i32>::ONE, Ordering::SeqCst), crate::number::YololNumber::<i32>::MIN);
+    assert_eq!(small.load(Ordering::SeqCst), crate::number::YololNumber::<i32>::MIN);
+}
+
+#[test]
+fn raw_slice_test()
+{
+    type SmallNum = crate::number::YololNumber<i64>;
+
+    let raw: Vec<i128> = vec![0, 1_500, -2_250, i64::MAX.into()];
+    let nums = YololNumber::try_from_raw_slice(&raw).unwrap();
+    assert_eq!(nums, &[yolol!(0), yolol!(1.5), yolol!(-2.25), YololNumber::MAX][..]);
+    assert_eq!(YololNumber::as_raw_slice(nums), &raw[..]);
+
+    assert_eq!(YololNumber::try_from_raw_slice(&[0, i64::MAX as i128 + 1]), Err(ConversionError::Overflow));
+    assert_eq!(YololNumber::try_from_raw_slice(&[i64::MIN as i128 - 1, 0]), Err(ConversionError::Underflow));
+    assert_eq!(YololNumber::try_from_raw_slice(&[]), Ok(&[][..]));
+
+    let mut raw: Vec<i64> = vec![1_000, 2_000, i64::MIN];
+    let nums = SmallNum::try_from_raw_slice_mut(&mut raw).unwrap();
+    for num in nums.iter_mut()
+    {
+        *num += SmallNum::from_inner_const(-500);
+    }
+    assert_eq!(raw, vec![500, 1_500, i64::MIN]);
+}
+
+#[test]
+fn batch_test()
+{
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use crate::batch;
+    use crate::number::report::{self, OpKind};
+
+    type SmallNum = crate::number::YololNumber<i64>;
+
+    let max = YololNumber::MAX;
+    let min = YololNumber::MIN;
+
+    let left = vec![yolol!(1.5), yolol!(-2), max, min, yolol!(0.001), max, yolol!(7), min];
+    let right = vec![yolol!(2.25), yolol!(-0.5), yolol!(1), yolol!(-1), yolol!(0.5), min, yolol!(0), max];
+    let mut out = vec![YololNumber::ZERO; left.len()];
+
+    let pairs = || left.iter().zip(&right).map(|(&l, &r)| (l, r));
+
+    batch::add_slices(&left, &right, &mut out);
+    assert_eq!(out, pairs().map(|(l, r)| l + r).collect::<Vec<_>>());
+    batch::sub_slices(&left, &right, &mut out);
+    assert_eq!(out, pairs().map(|(l, r)| l - r).collect::<Vec<_>>());
+    batch::mul_slices(&left, &right, &mut out);
+    assert_eq!(out, pairs().map(|(l, r)| l * r).collect::<Vec<_>>());
+    batch::div_slices(&left, &right, &mut out);
+    assert_eq!(out, pairs().map(|(l, r)| l / r).collect::<Vec<_>>());
+    batch::scale(&left, yolol!(-1.5), &mut out);
+    assert_eq!(out, left.iter().map(|&l| l * yolol!(-1.5)).collect::<Vec<_>>());
+    batch::sin_slice(&left, &mut out);
+    assert_eq!(out, left.iter().map(|l| l.sin()).collect::<Vec<_>>());
+    batch::tan_slice(&right, &mut out);
+    assert_eq!(out, right.iter().map(|r| r.tan()).collect::<Vec<_>>());
+
+    assert_eq!(batch::sum_saturating(&left), left.iter().fold(YololNumber::ZERO, |sum, &num| sum + num));
+    assert_eq!(batch::sum_saturating(&[max, yolol!(1), yolol!(-1)]), max - yolol!(1));
+    assert_eq!(batch::sum_saturating::<i128, crate::traits::StandardRange>(&[]), YololNumber::ZERO);
+
+    // Overflowing the inner type itself has to match too, which an i64 does at the standard range
+    let small_left = vec![SmallNum::MAX, SmallNum::MIN, SmallNum::MAX, SmallNum::from_inner_const(5)];
+    let small_right = vec![SmallNum::MAX, SmallNum::MIN, SmallNum::MIN, SmallNum::from_inner_const(-7)];
+    let mut small_out = vec![SmallNum::ZERO; 4];
+
+    batch::add_slices(&small_left, &small_right, &mut small_out);
+    assert_eq!(small_out, small_left.iter().zip(&small_right).map(|(&l, &r)| l + r).collect::<Vec<_>>());
+    batch::sub_slices(&small_left, &small_right, &mut small_out);
+    assert_eq!(small_out, small_left.iter().zip(&small_right).map(|(&l, &r)| l - r).collect::<Vec<_>>());
+
+    // The reports from every element get combined into one per call
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&seen);
+    report::set_observer(move |kind, op_report| sink.borrow_mut().push((kind, op_report)));
+
+    batch::add_slices(&left, &right, &mut out);
+    batch::sub_slices(&[yolol!(1)], &[yolol!(2)], &mut out[..1]);
+    batch::mul_slices(&left, &right, &mut out);
+    batch::sub_slices(&small_left[..1], &small_right[2..3], &mut small_out[..1]);
+
+    report::clear_observer();
+    assert_eq!(*seen.borrow(), vec![
+        (OpKind::Add, OpReport { saturated_high: true, saturated_low: true, ..OpReport::default() }),
+        (OpKind::Mul, OpReport { saturated_high: true, saturated_low: true, truncated: true, ..OpReport::default() }),
+        (OpKind::Sub, OpReport { saturated_high: true, ..OpReport::default() }),
+    ]);
+
+    let result = std::panic::catch_unwind(|| batch::add_slices(&[yolol!(1)], &[], &mut [YololNumber::ZERO]));
+    assert!(result.is_err());
+}
+
+#[test]
+fn shortest_literal_test()
+{
+    let round_trips = |num: YololNumber| {
+        let literal = num.to_shortest_literal();
+        assert_eq!(literal.parse::<YololNumber>(), Ok(num), "{:?}", literal);
+        literal
+    };
+
+    // A power is evaluated with `pow`, the same as the game does
+    let evaluates_to = |num: YololNumber| {
+        let literal = num.to_shortest_literal();
+        let (base, exponent) = literal.split_once('^').expect(&literal);
+
+        assert_eq!(base.parse::<YololNumber>().unwrap().pow(exponent.parse().unwrap()), num, "{:?}", literal);
+        literal
+    };
+
+    assert_eq!(round_trips(yolol!(0.5)), "0.5");
+    assert_eq!(round_trips(yolol!(-10.250)), "-10.25");
+    assert_eq!(round_trips(yolol!(-0)), "0");
+    assert_eq!(round_trips(yolol!(1000)), "1000");
+    assert_eq!(round_trips(yolol!(-1000000000)), "-1000000000");
+    assert_eq!(round_trips(YololNumber::MAX), "9223372036854775.807");
+    assert_eq!(round_trips(YololNumber::MIN), "-9223372036854775.808");
+    assert_eq!(evaluates_to(yolol!(100000)), "10^5");
+    assert_eq!(evaluates_to(yolol!(65536)), "4^8");
+
+    for raw in (-100_000..=100_000).chain(i64::MAX - 3_000..=i64::MAX).chain(i64::MIN..=i64::MIN + 3_000)
+    {
+        round_trips(YololNumber::from_raw(RawInner(raw)));
+    }
+
+    // Every power with a base up to 10^4 covers every power up to 10^8, so below that
+    // nothing the search could have found is shorter than what it returned
+    let limit = 100_000_000_i128;
+    let mut powers = std::collections::HashMap::new();
+
+    for base in 2..=10_000_i128
+    {
+        for exponent in (2..).take_while(|&exponent| base.pow(exponent) <= limit)
+        {
+            let value = base.pow(exponent);
+            let literal = format!("{}^{}", base, exponent);
+
+            if YololNumber::from_value(base).pow(YololNumber::from_value(exponent)) == YololNumber::from_value(value)
+            {
+                let shortest = powers.entry(value).or_insert_with(|| literal.clone());
+                if literal.len() < shortest.len() { *shortest = literal }
+            }
+        }
+    }
+
+    for (&value, power) in &powers
+    {
+        let num = YololNumber::from_value(value);
+        let literal = if power.len() < value.to_string().len() { evaluates_to(num) } else { round_trips(num) };
+
+        assert_eq!(literal.len(), power.len().min(value.to_string().len()), "{} for {}", literal, value);
+    }
+
+    for value in (2..=100_000).filter(|value| !powers.contains_key(value))
+    {
+        assert_eq!(round_trips(YololNumber::from_value(value)), value.to_string());
+    }
+}
+
+#[test]
+fn packer_test()
+{
+    use core::ops::RangeInclusive;
+    use crate::packer::Packer;
+
+    let packer = Packer::new([0..=4, -3..=3, 1..=10]).unwrap();
+    assert_eq!(packer.max_packed(), yolol!(349));
+
+    let mut seen = std::collections::HashSet::new();
+    for x in 0..=4
+    {
+        for y in -3..=3
+        {
+            for z in 1..=10
+            {
+                let values = [YololNumber::from_value(x), YololNumber::from_value(y), YololNumber::from_value(z)];
+                let packed = packer.pack(values).unwrap();
+
+                assert!(packed >= YololNumber::ZERO && packed <= packer.max_packed());
+                assert!(seen.insert(packed));
+                assert_eq!(packer.unpack(packed), values);
+                assert_eq!(packer.unpack_field(packed, 1), values[1]);
+            }
+        }
+    }
+
+    assert_eq!(packer.pack_source("a", ["x", "y", "z"]), "a=x+(y+3)*5+(z-1)*35");
+    assert_eq!(packer.unpack_source("a", ["x", "y", "z"]), "x=a%5 y=(a%35-a%5)/5-3 z=(a-a%35)/35+1");
+
+    assert_eq!(packer.pack([yolol!(5), yolol!(0), yolol!(1)]), Err(PackError::OutOfRange(0)));
+    assert_eq!(packer.pack([yolol!(0), yolol!(-4), yolol!(1)]), Err(PackError::OutOfRange(1)));
+    assert_eq!(packer.pack([yolol!(0), yolol!(0), yolol!(1.5)]), Err(PackError::OutOfRange(2)));
+
+    assert_eq!(Packer::new([0..=1, RangeInclusive::new(3, 2)]), Err(PackError::EmptyRange(1)));
+    assert_eq!(Packer::new([0..=9, 0..=922_337_203_685_477]), Err(PackError::TooLarge));
+    assert_eq!(Packer::new([0..=1, 0..=i64::MAX]), Err(PackError::TooLarge));
+
+    // The largest layout that fits still unpacks exactly
+    let packer = Packer::new([0..=4, 0..=1_844_674_407_370_954]).unwrap();
+    let values = [yolol!(4), yolol!(1_844_674_407_370_954)];
+    let packed = packer.pack(values).unwrap();
+
+    assert_eq!(packed, packer.max_packed());
+    assert_eq!(packed, yolol!(9_223_372_036_854_774));
+    assert_eq!(packer.unpack(packed), values);
+
+    // The weight of a field after every combination can be just past the range, which still works
+    let packer = Packer::new([0..=9_223_372_036_854_775, 0..=0]).unwrap();
+    let values = [yolol!(9_223_372_036_854_775), yolol!(0)];
+    assert_eq!(packer.unpack(packer.pack(values).unwrap()), values);
+}
+
+#[test]
+fn split_parts_test()
+{
+    use crate::number::conversions::from_str::{match_parts, split_parts};
+
+    let inputs = ["0", "1", "-1", "+1", "1.", "1.5", "1.05", "-0.000", "12.0340", "007.700", "", "-", "+", ".5", "1..5", "1.5.", "1a", "a1", "1.-5", "--1", " 1"];
+
+    for &input in &inputs
+    {
+        assert_eq!(split_parts(input), match_parts(input), "{:?}", input);
+    }
+}
+
+
+#[test]
+fn semantics_test()
+{
+    let standard = Semantics::STANDARD;
+    let strict = Semantics::STRICT;
+
+    assert_eq!(Semantics::default(), standard);
+
+    assert_eq!(YololNumber::add_in(&standard, YololNumber::max_value(), yolol!(0.001)), YololNumber::max_value());
+    assert_eq!(YololNumber::sub_in(&strict, YololNumber::min_value(), yolol!(0.001)), YololNumber::min_value());
+    assert_eq!(YololNumber::mul_in(&standard, yolol!(2.5), yolol!(-4)), yolol!(-10));
+    assert_eq!(YololNumber::mul_in(&strict, YololNumber::max_value(), yolol!(2)), YololNumber::max_value());
+
+    assert_eq!(YololNumber::div_in(&standard, yolol!(1), yolol!(0)), Some(yolol!(0)));
+    assert_eq!(YololNumber::div_in(&strict, yolol!(1), yolol!(0)), None);
+    assert_eq!(YololNumber::div_in(&strict, yolol!(1), yolol!(3)), Some(yolol!(0.333)));
+    assert_eq!(YololNumber::mod_in(&standard, yolol!(1), yolol!(0)), Some(yolol!(0)));
+    assert_eq!(YololNumber::mod_in(&strict, yolol!(1), yolol!(0)), None);
+    assert_eq!(YololNumber::mod_in(&strict, yolol!(-7), yolol!(3)), Some(yolol!(-1)));
+}
+
 #[test]
 fn trig_test()
 {
@@ -116,7 +1244,7 @@ fn trig_test()
 #[test]
 fn sin_test()
 {
-    let num: YololNumber = YololNumber::from_value(45);
+    let num: YololNumber = yolol!(45);
     println!("Num: {}", num);
     println!("Sin: {}", num.sin()); 
 }
@@ -124,7 +1252,7 @@ fn sin_test()
 #[test]
 fn cos_test()
 {
-    let num: YololNumber = YololNumber::from_value(60);
+    let num: YololNumber = yolol!(60);
     println!("Num: {}", num);
     println!("Cos: {}", num.cos()); 
 }
@@ -132,7 +1260,7 @@ fn cos_test()
 #[test]
 fn tan_test()
 {
-    let num: YololNumber = YololNumber::from_value(45);
+    let num: YololNumber = yolol!(45);
 
     println!("Test: {}", 45_f64.to_radians());
     println!("Num: {}", num);
@@ -142,8 +1270,8 @@ fn tan_test()
 #[test]
 fn pow_test()
 {
-    let num1 = YololNumber::from_inner(-1_000);
-    let num2 = YololNumber::from_inner(-0_500);
+    let num1 = YololNumber::from_raw(RawInner(-1_000));
+    let num2 = YololNumber::from_raw(RawInner(-0_500));
     
     let out = num1.pow(num2);
     println!("Pow out = {:?}", out);
@@ -152,7 +1280,7 @@ fn pow_test()
 #[test]
 fn sqrt_test()
 {
-    let num1 = YololNumber::from_value(-1);
+    let num1 = yolol!(-1);
     
     let out = num1.sqrt();
     println!("Sqrt out = {:?}", out);
@@ -0,0 +1,84 @@
+use core::fmt::Debug;
+
+/// Sets the range of values a `YololNumber` can express, independent of its backing type.
+/// The bounds are in raw inners, so a `MAX_INNER` of `1_000` means the largest value is `1`.
+///
+/// If the backing type can't express a bound, the bound of the backing type is used instead.
+/// The range must contain zero, and the product of any two values in it has to fit in the wide type
+/// of the backing type, see `Widen`. Using a `YololNumber` with a range that breaks either fails to compile.
+///
+/// ```compile_fail
+/// use yolol_number::number::YololNumber;
+/// use yolol_number::traits::ConstRange;
+///
+/// // The range doesn't contain zero
+/// let num = YololNumber::<i64, ConstRange<1_000, 2_000>>::from_value(1);
+/// ```
+///
+/// ```compile_fail
+/// use yolol_number::number::YololNumber;
+/// use yolol_number::traits::ConstRange;
+///
+/// // An i128 can't hold the product of two values this large
+/// let num = YololNumber::<i128, ConstRange<{ i128::MIN }, { i128::MAX }>>::from_value(1);
+/// ```
+pub trait YololRange: 'static + Copy + Debug
+{
+    /// The raw inner of the smallest expressible value.
+    const MIN_INNER: i128;
+    /// The raw inner of the largest expressible value.
+    const MAX_INNER: i128;
+}
+
+/// The range of a `YololNumber` in the current version of the game, which is the range of an `i64` raw inner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct StandardRange;
+
+impl YololRange for StandardRange
+{
+    const MIN_INNER: i128 = i64::MIN as i128;
+    const MAX_INNER: i128 = i64::MAX as i128;
+}
+
+/// A range set through const generics, for when defining a new type isn't worth it.
+/// `ConstRange<-1_000_000, 1_000_000>` expresses values from `-1000` to `1000`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ConstRange<const MIN_INNER: i128, const MAX_INNER: i128>;
+
+impl<const MIN: i128, const MAX: i128> YololRange for ConstRange<MIN, MAX>
+{
+    const MIN_INNER: i128 = MIN;
+    const MAX_INNER: i128 = MAX;
+}
+
+/// Whether the range keeps the invariants in the docs of `YololRange`, given the sizes in bytes of the backing
+/// type and its wide type. Bounds past the backing type are clamped to it first, the same as `min_value` does.
+pub(crate) const fn range_is_valid(min: i128, max: i128, backing_bytes: usize, wide_bytes: usize) -> bool
+{
+    let min = if min < signed_min(backing_bytes) { signed_min(backing_bytes) } else { min };
+    let max = if max > signed_max(backing_bytes) { signed_max(backing_bytes) } else { max };
+
+    // Shifting by the conversion value is a product too, which matters for tiny ranges
+    let largest = if min.unsigned_abs() > max.unsigned_abs() { min.unsigned_abs() } else { max.unsigned_abs() };
+    let factor = if largest > 1_000 { largest } else { 1_000 };
+
+    let product_fits = match largest.checked_mul(factor)
+    {
+        Some(product) => product <= signed_max(wide_bytes) as u128,
+        None => false,
+    };
+
+    min <= 0 && max >= 0 && product_fits
+}
+
+/// The largest value of a signed integer with the given size in bytes.
+const fn signed_max(bytes: usize) -> i128
+{
+    if bytes >= 16 { i128::MAX } else { (1 << (bytes * 8 - 1)) - 1 }
+}
+
+/// The smallest value of a signed integer with the given size in bytes.
+const fn signed_min(bytes: usize) -> i128
+{
+    -signed_max(bytes) - 1
+}
@@ -0,0 +1,71 @@
+use num_traits::{
+    CheckedAdd,
+    CheckedDiv,
+    CheckedMul,
+    CheckedRem,
+    CheckedSub,
+    NumCast,
+    One,
+    Signed,
+    Zero,
+};
+
+use crate::number::YololNumber;
+use crate::traits::{YololOps, YololRange};
+
+/// Gives a backing type a wider type to do intermediate math in. Multiplying two raw inners
+/// gives a value around a thousand times larger than the result, which would overflow the
+/// backing type long before the result does.
+pub trait Widen: Sized
+{
+    /// The type intermediate values are computed in. It must be able to express the product of any two values
+    /// in the range of a `YololNumber`, which is checked when compiling, see `YololRange`.
+    type Wide: Copy + Ord + Zero + One + Signed + NumCast + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + CheckedRem;
+
+    /// Converts the value into the wide type. This is always lossless.
+    fn widen(self) -> Self::Wide;
+
+    /// Converts a wide value back, failing if it doesn't fit.
+    fn narrow(wide: Self::Wide) -> Option<Self>;
+}
+
+/// Implements `Widen` for a primitive using another primitive as the wide type.
+macro_rules! impl_widen {
+    ( $( $t:ty => $wide:ty ),* ) => {
+        $(
+            impl Widen for $t
+            {
+                type Wide = $wide;
+
+                fn widen(self) -> Self::Wide
+                {
+                    <$wide as From<$t>>::from(self)
+                }
+
+                fn narrow(wide: Self::Wide) -> Option<Self>
+                {
+                    <$t as core::convert::TryFrom<$wide>>::try_from(wide).ok()
+                }
+            }
+        )*
+    }
+}
+
+// An i128 can already hold the product of any two values in the Yolol range, so it doesn't need widening
+impl_widen!(i32 => i64, i64 => i128, i128 => i128);
+
+// A `YololNumber` has to count as a backing type itself, so it can use the `YololOps` methods
+impl<T: YololOps, R: YololRange> Widen for YololNumber<T, R>
+{
+    type Wide = Self;
+
+    fn widen(self) -> Self::Wide
+    {
+        self
+    }
+
+    fn narrow(wide: Self::Wide) -> Option<Self>
+    {
+        Some(wide)
+    }
+}
@@ -1,5 +1,8 @@
+use crate::number::report::{self, OpKind, OpReport};
 use crate::traits::InnerBounds;
 
+/// The yolol flavoured operations on a backing type, which saturate at its bounds instead of overflowing.
+/// Whenever one saturates or maps a modulo by zero, the thread's observer is told, see `number::report`.
 pub trait YololOps: InnerBounds
 {
     fn yolol_add(self, right: Self) -> Self;
@@ -17,6 +20,14 @@ pub trait YololOps: InnerBounds
     fn would_underflow_mul(self, right: Self) -> bool;
 }
 
+/// Returns the maximum value if `high`, otherwise the minimum value, and tells the observer it saturated.
+fn saturated<T: InnerBounds>(kind: OpKind, high: bool) -> T
+{
+    report::notify(kind, OpReport::saturation(high));
+
+    if high { T::max_value() } else { T::min_value() }
+}
+
 impl<T: InnerBounds> YololOps for T
 {
     // For these, use this algorithm: https://stackoverflow.com/questions/199333/how-do-i-detect-unsigned-integer-multiply-overflow
@@ -28,11 +39,8 @@ impl<T: InnerBounds> YololOps for T
         {
             Some(num) => num,
 
-            None if self.would_overflow_add(right) => T::max_value(),
-            None if self.would_underflow_add(right) => T::min_value(),
-            None => {
-                panic!("[yolol_add] Unknown failure occurred with adding values! Operation: ({} + {})", self, right)
-            }
+            // Adding can only fail by going out of range, which is past the end the right side points to
+            None => saturated(OpKind::Add, !right.is_negative()),
         }
     }
 
@@ -42,11 +50,8 @@ impl<T: InnerBounds> YololOps for T
         {
             Some(num) => num,
 
-            None if self.would_overflow_add(-right) => T::max_value(),
-            None if self.would_underflow_add(-right) => T::min_value(),
-            None => {
-                panic!("[yolol_sub] Unknown failure occurred with subtracting values! Operation: ({} - {})", self, right)
-            }
+            // Subtracting can only fail by going out of range, which is past the end opposite the right side
+            None => saturated(OpKind::Sub, right.is_negative()),
         }
     }
 
@@ -56,11 +61,8 @@ impl<T: InnerBounds> YololOps for T
         {
             Some(num) => num,
 
-            None if self.would_overflow_mul(right) => T::max_value(),
-            None if self.would_underflow_mul(right) => T::min_value(),
-            None => {
-                panic!("[yolol_mul] Unknown failure occurred with multiplying values! Operation: ({} * {})", self, right)
-            }
+            // Multiplying can only fail by going out of range, and the signs tell us which end
+            None => saturated(OpKind::Mul, self.is_negative() == right.is_negative()),
         }
     }
 
@@ -71,26 +73,27 @@ impl<T: InnerBounds> YololOps for T
             Some(num) => Some(num),
 
             None if right == T::zero() => None,
-            None if self.would_overflow_mul(right) => Some(T::max_value()),
-            None if self.would_underflow_mul(right) => Some(T::min_value()),
-            None => {
-                panic!("[yolol_div] Unknown failure occurred with dividing values! Operation: ({} / {})", self, right)
-            }
+
+            // Otherwise dividing can only fail by going out of range, and the signs tell us which end
+            None => Some(saturated(OpKind::Div, self.is_negative() == right.is_negative())),
         }
     }
 
+    // The remainder takes the sign of the left side, and is never further from zero than the right side,
+    // so it can't go out of range. Taking the remainder of zero gives zero, like yolol division
     fn yolol_mod(self, right: Self) -> Self
     {
         match self.checked_rem(&right)
         {
             Some(num) => num,
 
-            None if right == T::zero() => T::zero(),
-            None if self.would_overflow_mul(T::one() / right) => T::max_value(),
-            None if self.would_underflow_mul(T::one() / right) => T::min_value(),
-            None => {
-                panic!("[yolol_mod] Unknown failure occurred with moduloing values! Operation: ({} % {})", self, right)
-            }
+            None if right == T::zero() => {
+                report::notify(OpKind::Mod, OpReport { div_by_zero_mapped: true, ..OpReport::default() });
+                T::zero()
+            },
+
+            // The only other failure is the minimum by `-1` overflowing the quotient, where the remainder is zero
+            None => T::zero(),
         }
     }
 
@@ -7,8 +7,13 @@
 /// Based on an existing implementation of "T op U" where both of T and U have `Copy`,
 /// this macro will implement "&T op U", "T op &U", and "&T op &U".
 macro_rules! impl_for_refs {
-    ( impl<$g:ident: $b:ident> $imp:ident for $t:ty { fn $method:ident() -> $u:ty } ) => {
-        impl<'a, $g: $b> $imp<$u> for &'a $t {
+    ( impl<$( $g:ident: $b:ident ),+> $imp:ident for $t:ty { fn $method:ident() -> $u:ty } ) => {
+        impl_for_refs!( impl<$( $g: $b ),+> $imp for $t where {} { fn $method() -> $u } );
+    };
+
+    // The where clause is wrapped in braces to keep the macro parsing unambiguous
+    ( impl<$( $g:ident: $b:ident ),+> $imp:ident for $t:ty where { $( $w:tt )* } { fn $method:ident() -> $u:ty } ) => {
+        impl<'a, $( $g: $b ),+> $imp<$u> for &'a $t where $( $w )* {
             type Output = <$t as $imp<$u>>::Output;
 
             fn $method(self, other: $u) -> <$t as $imp<$u>>::Output {
@@ -16,7 +21,7 @@ macro_rules! impl_for_refs {
             }
         }
 
-        impl<$g: $b> $imp<&$u> for $t {
+        impl<$( $g: $b ),+> $imp<&$u> for $t where $( $w )* {
             type Output = <$t as $imp<$u>>::Output;
 
             fn $method(self, other: &$u) -> <$t as $imp<$u>>::Output {
@@ -24,7 +29,7 @@ macro_rules! impl_for_refs {
             }
         }
 
-        impl<$g: $b> $imp<&$u> for &$t {
+        impl<$( $g: $b ),+> $imp<&$u> for &$t where $( $w )* {
             type Output = <$t as $imp<$u>>::Output;
 
             fn $method(self, other: &$u) -> <$t as $imp<$u>>::Output {
@@ -32,4 +37,16 @@ macro_rules! impl_for_refs {
             }
         }
     }
+}
+
+/// Based on an existing implementation of "T op= U" where U has `Copy`,
+/// this macro will implement "T op= &U".
+macro_rules! impl_assign_for_refs {
+    ( impl<$( $g:ident: $b:ident ),+> $imp:ident for $t:ty { fn $method:ident() -> $u:ty } ) => {
+        impl<$( $g: $b ),+> $imp<&$u> for $t {
+            fn $method(&mut self, other: &$u) {
+                $imp::$method(self, *other);
+            }
+        }
+    }
 }
\ No newline at end of file
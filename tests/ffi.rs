@@ -0,0 +1,62 @@
+//! Builds the static library, then compiles and runs a C program against it and the header.
+#![cfg(unix)]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The native libraries a Rust static library needs, as printed by `--print native-static-libs`.
+#[cfg(target_os = "macos")]
+const NATIVE_LIBS: &[&str] = &["-lSystem", "-lc", "-lm"];
+#[cfg(not(target_os = "macos"))]
+const NATIVE_LIBS: &[&str] = &["-lgcc_s", "-lutil", "-lrt", "-lpthread", "-lm", "-ldl", "-lc"];
+
+fn run(command: &mut Command)
+{
+    let status = command.status()
+        .unwrap_or_else(|error| panic!("Failed to run {:?}: {}", command, error));
+
+    assert!(status.success(), "{:?} failed with {}", command, status);
+}
+
+#[test]
+fn header_matches()
+{
+    let header = include_str!("../include/yolol_number.h");
+    let define = format!("#define YOLOL_FORMAT_BUFFER_LEN {}", yolol_number::ffi::YOLOL_FORMAT_BUFFER_LEN);
+
+    assert!(header.contains(&define), "The header doesn't have `{}`", define);
+}
+
+#[test]
+fn c_program()
+{
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("ffi");
+
+    // A C compiler isn't always around, but there's nothing to test without one
+    if Command::new("cc").arg("--version").output().is_err()
+    {
+        eprintln!("Skipping the C program since there's no `cc` to compile it with");
+        return;
+    }
+
+    // `cargo test` only builds the rlib, so the static library is built separately
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    run(Command::new(cargo)
+        .args(["build", "--lib", "--quiet", "--target-dir"])
+        .arg(&out_dir)
+        .arg("--manifest-path")
+        .arg(manifest_dir.join("Cargo.toml")));
+
+    let program = out_dir.join("ffi_test");
+    run(Command::new("cc")
+        .arg(manifest_dir.join("tests/ffi/ffi_test.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg(out_dir.join("debug/libyolol_number.a"))
+        .args(NATIVE_LIBS)
+        .arg("-o")
+        .arg(&program));
+
+    run(&mut Command::new(&program));
+}
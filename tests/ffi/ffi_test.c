@@ -0,0 +1,85 @@
+/* Exercises the C interface, exiting with a failure if any result isn't what the game gives. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "yolol_number.h"
+
+static int failures = 0;
+
+#define CHECK(condition) \
+    do { \
+        if (!(condition)) { \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #condition); \
+            failures++; \
+        } \
+    } while (0)
+
+static int64_t parse(const char *string)
+{
+    int64_t out = 0;
+    CHECK(yolol_parse(string, strlen(string), &out) == YOLOL_OK);
+    return out;
+}
+
+static int formats_as(int64_t input, const char *expected)
+{
+    char buf[YOLOL_FORMAT_BUFFER_LEN];
+    size_t written = 0;
+
+    if (yolol_format(input, buf, sizeof buf, &written) != YOLOL_OK)
+    {
+        return 0;
+    }
+
+    return written == strlen(expected) && strcmp(buf, expected) == 0;
+}
+
+int main(void)
+{
+    int64_t out = 0;
+
+    CHECK(yolol_add(1500, 2250, &out) == YOLOL_OK && out == 3750);
+    CHECK(yolol_add(INT64_MAX, 1, &out) == YOLOL_OK && out == INT64_MAX);
+    CHECK(yolol_sub(INT64_MIN, 1, &out) == YOLOL_OK && out == INT64_MIN);
+    CHECK(yolol_mul(1500, -2000, &out) == YOLOL_OK && out == -3000);
+    CHECK(yolol_mul(1, 500, &out) == YOLOL_OK && out == 0);
+    CHECK(yolol_div(1000, 3000, &out) == YOLOL_OK && out == 333);
+    CHECK(yolol_mod(-7000, 3000, &out) == YOLOL_OK && out == -1000);
+
+    out = 42;
+    CHECK(yolol_div(1000, 0, &out) == YOLOL_DIVIDE_BY_ZERO && out == 0);
+    CHECK(yolol_mod(1000, 0, &out) == YOLOL_DIVIDE_BY_ZERO && out == 0);
+    CHECK(yolol_add(1, 2, NULL) == YOLOL_NULL_POINTER);
+
+    CHECK(yolol_sqrt(2000, &out) == YOLOL_OK && out == 1414);
+    CHECK(yolol_sin(30000, &out) == YOLOL_OK && out == 500);
+    CHECK(yolol_cos(60000, &out) == YOLOL_OK && out == 500);
+    CHECK(yolol_tan(45000, &out) == YOLOL_OK && out == 1000);
+    CHECK(yolol_asin(1000, &out) == YOLOL_OK && out == 90000);
+    CHECK(yolol_acos(0, &out) == YOLOL_OK && out == 90000);
+    CHECK(yolol_atan(1000, &out) == YOLOL_OK && out == 45000);
+
+    CHECK(parse("-12.345") == -12345);
+    CHECK(parse("0.0019") == 1);
+    CHECK(parse("99999999999999999999") == INT64_MAX);
+    CHECK(yolol_parse("1.5x", 4, &out) == YOLOL_PARSE_ERROR);
+    CHECK(yolol_parse("12", 1, &out) == YOLOL_OK && out == 1000);
+    CHECK(yolol_parse(NULL, 0, &out) == YOLOL_NULL_POINTER);
+
+    CHECK(formats_as(-12340, "-12.34"));
+    CHECK(formats_as(0, "0"));
+    CHECK(formats_as(INT64_MIN, "-9223372036854775.808"));
+
+    char small[3];
+    CHECK(yolol_format(-1500, small, sizeof small, NULL) == YOLOL_BUFFER_TOO_SMALL);
+    CHECK(yolol_format(7000, small, sizeof small, NULL) == YOLOL_OK && strcmp(small, "7") == 0);
+
+    if (failures != 0)
+    {
+        fprintf(stderr, "%d checks failed\n", failures);
+        return 1;
+    }
+
+    return 0;
+}